@@ -0,0 +1,223 @@
+//! The International Standard Atmosphere.
+//!
+//! The ISA (ISO 2533, identical to the U.S. Standard Atmosphere 1976 up to
+//! 86 km) models the atmosphere as layers in which temperature changes
+//! linearly with geopotential altitude, starting from 15 °C and 101,325 Pa
+//! at sea level. These functions give the standard temperature, pressure and
+//! density from 2,000 m below sea level to 84,852 m geopotential, which is
+//! 86 km geometric, and the ISA deviation of an observed temperature, as in
+//! "ISA+15".
+//!
+//! Altitudes are geopotential metres, as used by aviation; convert geometric
+//! heights with [`geopotential`]. Pressures are in pascals and densities in
+//! kg/m³.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::atmosphere;
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! let cruise = atmosphere::temperature(11_000.0).unwrap();
+//! assert_eq!(format!("{:.2}", cruise.to(TemperatureUnit::Celsius)), "-56.50\u{00B0}C");
+//!
+//! let observed = Temperature::new(30.0, TemperatureUnit::Celsius).unwrap();
+//! let deviation = atmosphere::deviation(observed, 0.0).unwrap();
+//! assert_eq!(format!("ISA{deviation:+}"), "ISA+15\u{00B0}C");
+//! ```
+
+use crate::delta::TemperatureDelta;
+use crate::temperature::{Result, Temperature, TemperatureError, TemperatureUnit};
+
+/// Standard acceleration of gravity, in m/s².
+const GRAVITY: f64 = 9.806_65;
+
+/// Specific gas constant of dry air, in J/(kg·K).
+const GAS_CONSTANT: f64 = 287.052_87;
+
+/// Earth's radius for converting geometric to geopotential altitude, in
+/// metres.
+const EARTH_RADIUS: f64 = 6_356_766.0;
+
+/// Sea-level pressure, in pascals.
+const SEA_LEVEL_PRESSURE: f64 = 101_325.0;
+
+/// Lowest altitude of the model, in geopotential metres.
+const BOTTOM: f64 = -2_000.0;
+
+/// Highest altitude of the model, in geopotential metres.
+const TOP: f64 = 84_852.0;
+
+/// A layer of the atmosphere, from its base up to the next layer's base.
+struct Layer {
+    /// Geopotential altitude of the base, in metres.
+    base: f64,
+    /// Temperature at the base, in kelvin.
+    temperature: f64,
+    /// Change of temperature with altitude, in K/m.
+    lapse_rate: f64,
+}
+
+/// The layers, lowest first: troposphere, tropopause, two stratosphere
+/// layers, stratopause and two mesosphere layers. The troposphere extends
+/// below sea level.
+#[rustfmt::skip]
+const LAYERS: [Layer; 7] = [
+    Layer { base: 0.0, temperature: 288.15, lapse_rate: -0.0065 },
+    Layer { base: 11_000.0, temperature: 216.65, lapse_rate: 0.0 },
+    Layer { base: 20_000.0, temperature: 216.65, lapse_rate: 0.001 },
+    Layer { base: 32_000.0, temperature: 228.65, lapse_rate: 0.0028 },
+    Layer { base: 47_000.0, temperature: 270.65, lapse_rate: 0.0 },
+    Layer { base: 51_000.0, temperature: 270.65, lapse_rate: -0.0028 },
+    Layer { base: 71_000.0, temperature: 214.65, lapse_rate: -0.002 },
+];
+
+impl Layer {
+    /// Returns the temperature, in kelvin, `altitude` metres into the layer.
+    fn temperature_at(&self, altitude: f64) -> f64 {
+        self.temperature + self.lapse_rate * (altitude - self.base)
+    }
+
+    /// Returns the pressure at `altitude`, given the pressure at the base,
+    /// from the hydrostatic equation.
+    fn pressure_at(&self, altitude: f64, base_pressure: f64) -> f64 {
+        if self.lapse_rate == 0.0 {
+            let scale_height = GAS_CONSTANT * self.temperature / GRAVITY;
+            base_pressure * (-(altitude - self.base) / scale_height).exp()
+        } else {
+            let ratio = self.temperature / self.temperature_at(altitude);
+            base_pressure * ratio.powf(GRAVITY / (GAS_CONSTANT * self.lapse_rate))
+        }
+    }
+}
+
+/// Checks that `altitude` is within the model.
+fn check(altitude: f64) -> Result<()> {
+    if (BOTTOM..=TOP).contains(&altitude) {
+        Ok(())
+    } else {
+        Err(TemperatureError::OutOfDomain {
+            quantity: "altitude",
+            value: altitude,
+            unit: "m",
+            min: BOTTOM,
+            max: TOP,
+        })
+    }
+}
+
+/// Returns the layer `altitude` falls in, with the pressure at its base.
+fn layer(altitude: f64) -> (&'static Layer, f64) {
+    let mut pressure = SEA_LEVEL_PRESSURE;
+    let mut current = &LAYERS[0];
+    for next in &LAYERS[1..] {
+        if altitude < next.base {
+            break;
+        }
+        pressure = current.pressure_at(next.base, pressure);
+        current = next;
+    }
+    (current, pressure)
+}
+
+/// Converts a geometric height above sea level to geopotential altitude,
+/// both in metres.
+///
+/// Gravity weakens with height, so a geopotential metre is slightly more
+/// than a geometric one: 86 km geometric is 84,852 m geopotential.
+pub fn geopotential(geometric: f64) -> f64 {
+    EARTH_RADIUS * geometric / (EARTH_RADIUS + geometric)
+}
+
+/// Returns the standard temperature at `altitude`, in kelvin.
+///
+/// Fails with `OutOfDomain` outside -2,000 m to 84,852 m.
+pub fn temperature(altitude: f64) -> Result<Temperature> {
+    check(altitude)?;
+    let (layer, _) = layer(altitude);
+    Temperature::new(layer.temperature_at(altitude), TemperatureUnit::Kelvin)
+}
+
+/// Returns the standard pressure at `altitude`, in pascals.
+///
+/// Fails with `OutOfDomain` outside -2,000 m to 84,852 m.
+pub fn pressure(altitude: f64) -> Result<f64> {
+    check(altitude)?;
+    let (layer, base_pressure) = layer(altitude);
+    Ok(layer.pressure_at(altitude, base_pressure))
+}
+
+/// Returns the standard air density at `altitude`, in kg/m³.
+///
+/// Fails with `OutOfDomain` outside -2,000 m to 84,852 m.
+pub fn density(altitude: f64) -> Result<f64> {
+    check(altitude)?;
+    let (layer, base_pressure) = layer(altitude);
+    let pressure = layer.pressure_at(altitude, base_pressure);
+    Ok(pressure / (GAS_CONSTANT * layer.temperature_at(altitude)))
+}
+
+/// Returns how much warmer `observed` is than the standard temperature at
+/// `altitude`, in `observed`'s unit. A result of 15 °C is "ISA+15".
+///
+/// Fails with `OutOfDomain` outside -2,000 m to 84,852 m.
+pub fn deviation(observed: Temperature, altitude: f64) -> Result<TemperatureDelta> {
+    Ok(observed - temperature(altitude)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{density, deviation, geopotential, pressure, temperature};
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+
+    fn assert_close(actual: f64, expected: f64, relative: f64) {
+        assert!(
+            ((actual - expected) / expected).abs() < relative,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn matches_standard_tables() {
+        // U.S. Standard Atmosphere 1976, tables I and III, by geopotential
+        // altitude.
+        let table = [
+            (-2_000.0, 301.15, 1.27783e5, 1.4782),
+            (0.0, 288.15, 1.01325e5, 1.2250),
+            (11_000.0, 216.65, 2.26321e4, 3.6392e-1),
+            (20_000.0, 216.65, 5.47489e3, 8.8035e-2),
+            (32_000.0, 228.65, 8.68019e2, 1.3225e-2),
+            (47_000.0, 270.65, 1.10906e2, 1.4275e-3),
+            (51_000.0, 270.65, 6.69389e1, 8.6160e-4),
+            (71_000.0, 214.65, 3.95642, 6.4211e-5),
+            (84_852.0, 186.946, 3.73384e-1, 6.958e-6),
+        ];
+        for (altitude, kelvin, pascals, kg_per_m3) in table {
+            let temp = temperature(altitude).unwrap();
+            assert_eq!(temp.unit(), TemperatureUnit::Kelvin);
+            assert_close(temp.value(), kelvin, 1e-6);
+            assert_close(pressure(altitude).unwrap(), pascals, 1e-4);
+            assert_close(density(altitude).unwrap(), kg_per_m3, 1e-3);
+        }
+    }
+
+    #[test]
+    fn reports_isa_deviation() {
+        let observed = Temperature::new(30.0, TemperatureUnit::Celsius).unwrap();
+        assert_eq!(deviation(observed, 0.0).unwrap().value(), 15.0);
+
+        let observed = Temperature::new(-40.0, TemperatureUnit::Fahrenheit).unwrap();
+        let below = deviation(observed, 11_000.0).unwrap();
+        assert_eq!(below.unit(), TemperatureUnit::Fahrenheit);
+        assert!((below.to(TemperatureUnit::Celsius).value() - 16.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn geopotential_altitude() {
+        assert_eq!(geopotential(0.0), 0.0);
+        assert!((geopotential(86_000.0) - 84_852.0).abs() < 1.0);
+
+        let err = temperature(90_000.0).unwrap_err();
+        assert!(matches!(err, TemperatureError::OutOfDomain { .. }));
+        assert!(pressure(-3_000.0).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+//! Unit codes from data-file vocabularies.
+//!
+//! Clinical data (HL7, FHIR) labels temperatures with UCUM codes such as
+//! `Cel` and `[degF]`. Climate data (NetCDF with CF conventions) uses
+//! UDUNITS-2 strings such as `K`, `degC` or `degree_Celsius`. These methods
+//! map both vocabularies to `TemperatureUnit` and back. Delisle, Newton and
+//! Rømer have no code in either vocabulary, and Réaumur has none in UDUNITS.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! let unit = TemperatureUnit::from_code("degree_Celsius").unwrap();
+//! let reading = Temperature::new(21.5, unit).unwrap();
+//! assert_eq!(reading.to(TemperatureUnit::Kelvin).value(), 294.65);
+//!
+//! assert_eq!(TemperatureUnit::Fahrenheit.ucum(), Some("[degF]"));
+//! assert_eq!(TemperatureUnit::Celsius.udunits(), Some("degC"));
+//! ```
+
+use crate::temperature::TemperatureUnit;
+
+impl TemperatureUnit {
+    /// Looks up a unit by its UCUM code.
+    ///
+    /// Accepts the case-sensitive codes (`Cel`, `[degF]`, `K`, `[degR]`,
+    /// `[degRe]`) and their case-insensitive, all-uppercase forms (`CEL`,
+    /// `[DEGF]`, ...).
+    pub fn from_ucum(code: &str) -> Option<TemperatureUnit> {
+        TemperatureUnit::ALL.into_iter().find(|unit| {
+            unit.ucum().is_some_and(|ucum| {
+                code == ucum
+                    || code
+                        .chars()
+                        .eq(ucum.chars().map(|c| c.to_ascii_uppercase()))
+            })
+        })
+    }
+
+    /// Returns the case-sensitive UCUM code, if UCUM defines one.
+    pub const fn ucum(self) -> Option<&'static str> {
+        self.scale().ucum
+    }
+
+    /// Looks up a unit by its UDUNITS-2 symbol or name, as found in CF
+    /// `units` attributes.
+    ///
+    /// Accepts the symbols (`degC`, `degF`, `K`, `degR`), the names in any
+    /// case and optionally plural (`celsius`, `kelvins`), and `deg`,
+    /// `degree` or `degrees` followed by an optional underscore and the
+    /// symbol letter or name (`deg_C`, `degree_Celsius`, `degrees_F`).
+    pub fn from_udunits(spec: &str) -> Option<TemperatureUnit> {
+        let spec = spec.trim();
+        TemperatureUnit::ALL.into_iter().find(|unit| {
+            let scale = unit.scale();
+            let Some(symbol) = scale.udunits else {
+                return false;
+            };
+            let letter = symbol.strip_prefix("deg").unwrap_or(symbol);
+            let is_name = |name: &str| {
+                name.eq_ignore_ascii_case(scale.name)
+                    || name
+                        .strip_suffix(['s', 'S'])
+                        .is_some_and(|name| name.eq_ignore_ascii_case(scale.name))
+            };
+
+            if spec == symbol || is_name(spec) {
+                return true;
+            }
+            if spec.strip_prefix('\u{00B0}') == Some(letter) {
+                return true;
+            }
+            ["degrees", "degree", "deg"].into_iter().any(|prefix| {
+                strip_prefix_ignore_case(spec, prefix).is_some_and(|rest| {
+                    let rest = rest.strip_prefix('_').unwrap_or(rest);
+                    rest == letter || rest.eq_ignore_ascii_case(scale.name)
+                })
+            })
+        })
+    }
+
+    /// Returns the UDUNITS-2 symbol, if UDUNITS defines one. CF metadata
+    /// accepts these in `units` attributes.
+    pub const fn udunits(self) -> Option<&'static str> {
+        self.scale().udunits
+    }
+
+    /// Looks up a unit by a code from either vocabulary, trying UCUM first.
+    pub fn from_code(code: &str) -> Option<TemperatureUnit> {
+        TemperatureUnit::from_ucum(code).or_else(|| TemperatureUnit::from_udunits(code))
+    }
+}
+
+/// Removes an ASCII `prefix` from `input`, ignoring case.
+fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = input.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &input[prefix.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::temperature::TemperatureUnit;
+
+    #[test]
+    fn parses_ucum_codes() {
+        assert_eq!(
+            TemperatureUnit::from_ucum("Cel"),
+            Some(TemperatureUnit::Celsius)
+        );
+        assert_eq!(
+            TemperatureUnit::from_ucum("CEL"),
+            Some(TemperatureUnit::Celsius)
+        );
+        assert_eq!(
+            TemperatureUnit::from_ucum("[degF]"),
+            Some(TemperatureUnit::Fahrenheit)
+        );
+        assert_eq!(
+            TemperatureUnit::from_ucum("[DEGF]"),
+            Some(TemperatureUnit::Fahrenheit)
+        );
+        assert_eq!(
+            TemperatureUnit::from_ucum("K"),
+            Some(TemperatureUnit::Kelvin)
+        );
+        assert_eq!(
+            TemperatureUnit::from_ucum("[degRe]"),
+            Some(TemperatureUnit::Reaumur)
+        );
+        assert_eq!(TemperatureUnit::from_ucum("cel"), None);
+        assert_eq!(TemperatureUnit::from_ucum("degC"), None);
+    }
+
+    #[test]
+    fn parses_udunits_and_cf_strings() {
+        let cases = [
+            ("degC", TemperatureUnit::Celsius),
+            ("deg_C", TemperatureUnit::Celsius),
+            ("degree_Celsius", TemperatureUnit::Celsius),
+            ("degrees_Celsius", TemperatureUnit::Celsius),
+            ("celsius", TemperatureUnit::Celsius),
+            ("\u{00B0}C", TemperatureUnit::Celsius),
+            ("degF", TemperatureUnit::Fahrenheit),
+            ("degrees_F", TemperatureUnit::Fahrenheit),
+            ("K", TemperatureUnit::Kelvin),
+            ("kelvins", TemperatureUnit::Kelvin),
+            ("degK", TemperatureUnit::Kelvin),
+            ("degR", TemperatureUnit::Rankine),
+            ("degree_Rankine", TemperatureUnit::Rankine),
+        ];
+        for (spec, unit) in cases {
+            assert_eq!(TemperatureUnit::from_udunits(spec), Some(unit), "{spec}");
+        }
+
+        assert_eq!(TemperatureUnit::from_udunits("degrees_north"), None);
+        assert_eq!(TemperatureUnit::from_udunits("degc"), None);
+        assert_eq!(TemperatureUnit::from_udunits("Cel"), None);
+    }
+
+    #[test]
+    fn canonical_codes_round_trip() {
+        for unit in TemperatureUnit::ALL {
+            if let Some(code) = unit.ucum() {
+                assert_eq!(TemperatureUnit::from_ucum(code), Some(unit), "{code}");
+            }
+            if let Some(symbol) = unit.udunits() {
+                assert_eq!(
+                    TemperatureUnit::from_udunits(symbol),
+                    Some(unit),
+                    "{symbol}"
+                );
+            }
+        }
+        assert_eq!(TemperatureUnit::Delisle.ucum(), None);
+        assert_eq!(TemperatureUnit::Reaumur.udunits(), None);
+        assert_eq!(
+            TemperatureUnit::from_code("[degF]"),
+            Some(TemperatureUnit::Fahrenheit)
+        );
+        assert_eq!(
+            TemperatureUnit::from_code("degree_F"),
+            Some(TemperatureUnit::Fahrenheit)
+        );
+    }
+}
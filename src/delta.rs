@@ -0,0 +1,273 @@
+//! Temperature differences.
+//!
+//! A `TemperatureDelta` is an interval on a temperature scale, like "a rise of
+//! 10 °C". Unlike a `Temperature` it has no zero point, so converting it only
+//! rescales the value: a rise of 10 °C is a rise of 18 °F, not 50 °F.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::{Temperature, TemperatureDelta, TemperatureUnit};
+//!
+//! let morning = Temperature::new(15.0, TemperatureUnit::Celsius).unwrap();
+//! let afternoon = Temperature::new(77.0, TemperatureUnit::Fahrenheit).unwrap();
+//!
+//! let rise = afternoon - morning;
+//! assert_eq!(format!("{rise}"), "18\u{00B0}F");
+//! assert_eq!(format!("{}", rise.to(TemperatureUnit::Celsius)), "10\u{00B0}C");
+//!
+//! let evening = afternoon - TemperatureDelta::new(5.0, TemperatureUnit::Kelvin);
+//! assert_eq!(format!("{evening}"), "68\u{00B0}F");
+//! ```
+
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::format;
+use crate::numeric::sealed::Sealed;
+use crate::scale::degree_ratio;
+use crate::temperature::{Result, Temperature, TemperatureUnit};
+
+/// A difference between two temperatures, in the given unit's degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemperatureDelta {
+    value: f64,
+    unit: TemperatureUnit,
+}
+
+impl TemperatureDelta {
+    /// Creates a new temperature difference.
+    pub const fn new(value: f64, unit: TemperatureUnit) -> TemperatureDelta {
+        TemperatureDelta { value, unit }
+    }
+
+    /// Returns the size of the difference, in this difference's unit.
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit whose degrees the difference is measured in.
+    pub const fn unit(&self) -> TemperatureUnit {
+        self.unit
+    }
+
+    /// Converts this difference to the requested unit.
+    ///
+    /// Only the size of a degree matters, so the scales' offsets are ignored.
+    /// Differences on the Delisle scale change sign, because it runs
+    /// backwards.
+    pub fn to(&self, unit: TemperatureUnit) -> TemperatureDelta {
+        if self.unit == unit {
+            return *self;
+        }
+
+        let ratio = degree_ratio(self.unit, unit);
+        TemperatureDelta {
+            value: self.value * ratio.numerator as f64 / ratio.denominator as f64,
+            unit,
+        }
+    }
+}
+
+/// Honours the same flags as `Temperature`, including `{:#}` for the long
+/// form.
+impl fmt::Display for TemperatureDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let sign_plus = f.sign_plus();
+        let long = f.alternate();
+        format::pad(f, |w| {
+            format::write_plus(w, sign_plus, self.value)?;
+            self.value.fmt_value(w, precision)?;
+            let singular = format::is_singular(self.value, precision);
+            format::write_unit(w, self.unit, long, singular)
+        })
+    }
+}
+
+impl Temperature {
+    /// Adds a difference, rejecting results below absolute zero.
+    ///
+    /// The result is in this temperature's unit.
+    pub fn checked_add(self, delta: TemperatureDelta) -> Result<Temperature> {
+        Temperature::new(self.value() + delta.to(self.unit()).value, self.unit())
+    }
+
+    /// Subtracts a difference, rejecting results below absolute zero.
+    ///
+    /// The result is in this temperature's unit.
+    pub fn checked_sub(self, delta: TemperatureDelta) -> Result<Temperature> {
+        self.checked_add(-delta)
+    }
+}
+
+/// The difference between two temperatures, in the left-hand unit.
+impl Sub for Temperature {
+    type Output = TemperatureDelta;
+
+    fn sub(self, other: Temperature) -> TemperatureDelta {
+        TemperatureDelta {
+            value: self.value() - other.to(self.unit()).value(),
+            unit: self.unit(),
+        }
+    }
+}
+
+/// Adds a difference to a temperature, in the temperature's unit.
+///
+/// # Panics
+/// Panics if the result is below absolute zero; use
+/// [`Temperature::checked_add`] to handle that case.
+impl Add<TemperatureDelta> for Temperature {
+    type Output = Temperature;
+
+    fn add(self, delta: TemperatureDelta) -> Temperature {
+        match self.checked_add(delta) {
+            Ok(temp) => temp,
+            Err(err) => panic!("{self} + {delta}: {err}"),
+        }
+    }
+}
+
+/// Subtracts a difference from a temperature, in the temperature's unit.
+///
+/// # Panics
+/// Panics if the result is below absolute zero; use
+/// [`Temperature::checked_sub`] to handle that case.
+impl Sub<TemperatureDelta> for Temperature {
+    type Output = Temperature;
+
+    fn sub(self, delta: TemperatureDelta) -> Temperature {
+        match self.checked_sub(delta) {
+            Ok(temp) => temp,
+            Err(err) => panic!("{self} - {delta}: {err}"),
+        }
+    }
+}
+
+/// Adds two differences, in the left-hand unit.
+impl Add for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn add(self, other: TemperatureDelta) -> TemperatureDelta {
+        TemperatureDelta {
+            value: self.value + other.to(self.unit).value,
+            unit: self.unit,
+        }
+    }
+}
+
+/// Subtracts two differences, in the left-hand unit.
+impl Sub for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn sub(self, other: TemperatureDelta) -> TemperatureDelta {
+        self + -other
+    }
+}
+
+impl Neg for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn neg(self) -> TemperatureDelta {
+        TemperatureDelta {
+            value: -self.value,
+            unit: self.unit,
+        }
+    }
+}
+
+impl Mul<f64> for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn mul(self, factor: f64) -> TemperatureDelta {
+        TemperatureDelta {
+            value: self.value * factor,
+            unit: self.unit,
+        }
+    }
+}
+
+impl Div<f64> for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn div(self, divisor: f64) -> TemperatureDelta {
+        TemperatureDelta {
+            value: self.value / divisor,
+            unit: self.unit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TemperatureDelta;
+    use crate::temperature::{Temperature, TemperatureUnit, BOILING_POINT, FREEZING_POINT};
+
+    fn assert_close(actual: f64, expected: f64, epsilon: f64) {
+        assert!(
+            (actual - expected).abs() <= epsilon,
+            "expected {expected}, got {actual} (epsilon {epsilon})"
+        );
+    }
+
+    #[test]
+    fn delta_conversion_ignores_offsets() {
+        let rise = TemperatureDelta::new(10.0, TemperatureUnit::Celsius);
+        assert_close(rise.to(TemperatureUnit::Fahrenheit).value(), 18.0, 1e-12);
+        assert_close(rise.to(TemperatureUnit::Kelvin).value(), 10.0, 1e-12);
+        assert_close(rise.to(TemperatureUnit::Rankine).value(), 18.0, 1e-12);
+        assert_close(rise.to(TemperatureUnit::Reaumur).value(), 8.0, 1e-12);
+        assert_close(rise.to(TemperatureUnit::Delisle).value(), -15.0, 1e-12);
+        assert_close(rise.to(TemperatureUnit::Newton).value(), 3.3, 1e-12);
+        assert_close(rise.to(TemperatureUnit::Romer).value(), 5.25, 1e-12);
+    }
+
+    #[test]
+    fn temperature_difference_across_units() {
+        let boiling_f = BOILING_POINT.to(TemperatureUnit::Fahrenheit);
+        let delta = boiling_f - FREEZING_POINT;
+        assert_eq!(delta.unit(), TemperatureUnit::Fahrenheit);
+        assert_close(delta.value(), 180.0, 1e-12);
+
+        let delta = FREEZING_POINT - boiling_f;
+        assert_eq!(delta.unit(), TemperatureUnit::Celsius);
+        assert_close(delta.value(), -100.0, 1e-12);
+    }
+
+    #[test]
+    fn add_and_subtract_delta() {
+        let room = Temperature::new(20.0, TemperatureUnit::Celsius).expect("valid");
+        let warmer = room + TemperatureDelta::new(9.0, TemperatureUnit::Fahrenheit);
+        assert_eq!(warmer.unit(), TemperatureUnit::Celsius);
+        assert_close(warmer.value(), 25.0, 1e-12);
+
+        let cooler = room - TemperatureDelta::new(5.0, TemperatureUnit::Kelvin);
+        assert_close(cooler.value(), 15.0, 1e-12);
+    }
+
+    #[test]
+    fn checked_add_rejects_below_absolute_zero() {
+        let cold = Temperature::new(10.0, TemperatureUnit::Kelvin).expect("valid");
+        let drop = TemperatureDelta::new(-20.0, TemperatureUnit::Celsius);
+        assert!(cold.checked_add(drop).is_err());
+        assert!(cold.checked_sub(-drop).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_panics_below_absolute_zero() {
+        let cold = Temperature::new(10.0, TemperatureUnit::Kelvin).expect("valid");
+        let _ = cold + TemperatureDelta::new(-20.0, TemperatureUnit::Kelvin);
+    }
+
+    #[test]
+    fn delta_arithmetic() {
+        let a = TemperatureDelta::new(10.0, TemperatureUnit::Celsius);
+        let b = TemperatureDelta::new(9.0, TemperatureUnit::Fahrenheit);
+        assert_close((a + b).value(), 15.0, 1e-12);
+        assert_close((a - b).value(), 5.0, 1e-12);
+        assert_close((a * 3.0).value(), 30.0, 1e-12);
+        assert_close((a / 4.0).value(), 2.5, 1e-12);
+        assert_eq!(format!("{}", -a), "-10\u{00B0}C");
+    }
+}
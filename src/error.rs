@@ -0,0 +1,198 @@
+//! The crate's error type.
+//!
+//! Every fallible operation returns a `TemperatureError`, which records what
+//! went wrong: the offending value and unit for out-of-range temperatures,
+//! and the byte span of the input for parse failures. A number that doesn't
+//! parse as a float keeps the `ParseFloatError` as its `source()`.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::{Temperature, TemperatureError, TemperatureUnit};
+//!
+//! let err = Temperature::new(-300.0, TemperatureUnit::Celsius).unwrap_err();
+//! assert_eq!(format!("{err}"), "-300\u{00B0}C is below absolute zero");
+//!
+//! let err = "12,5C".parse::<Temperature>().unwrap_err();
+//! assert!(matches!(err, TemperatureError::InvalidNumber { .. }));
+//! assert_eq!(err.span(), Some(0..4));
+//! ```
+
+use core::fmt;
+use core::num::ParseFloatError;
+use core::ops::Range;
+
+use crate::temperature::{Temperature, TemperatureUnit};
+
+/// Result alias for fallible temperature operations.
+pub type Result<T> = core::result::Result<T, TemperatureError>;
+
+/// Errors from creating, converting or parsing a temperature.
+///
+/// Spans are byte ranges into the string that was parsed.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TemperatureError {
+    /// The value is colder than absolute zero on its scale.
+    BelowAbsoluteZero { value: f64, unit: TemperatureUnit },
+    /// The value is NaN or infinite.
+    NonFinite { value: f64, unit: TemperatureUnit },
+    /// The value, or the result of converting it, doesn't fit in the value
+    /// type.
+    OutOfRange { value: f64, unit: TemperatureUnit },
+    /// The temperature is outside the valid range `min` to `max`: the
+    /// bounds of a `Bounded` validator, or the range a model covers, such
+    /// as a thermocouple type's. Inputs other than temperatures use
+    /// `OutOfDomain`.
+    OutOfBounds {
+        value: f64,
+        unit: TemperatureUnit,
+        min: Temperature,
+        max: Temperature,
+    },
+    /// An input other than a temperature, such as a pressure, is outside
+    /// the range a model covers. `unit` applies to `value`, `min` and
+    /// `max`.
+    OutOfDomain {
+        quantity: &'static str,
+        value: f64,
+        unit: &'static str,
+        min: f64,
+        max: f64,
+    },
+    /// A measurement uncertainty is negative or not finite.
+    InvalidUncertainty { value: f64 },
+    /// A range's colder end is hotter than its other end.
+    InvertedRange { low: Temperature, high: Temperature },
+    /// The input was empty or only whitespace.
+    Empty,
+    /// The input has no unit; the span is where one was expected.
+    MissingUnit { span: Range<usize> },
+    /// The unit is not recognized; `unit` is its last character.
+    InvalidUnit { unit: char, span: Range<usize> },
+    /// The number is malformed.
+    InvalidNumber {
+        span: Range<usize>,
+        source: Option<ParseFloatError>,
+    },
+}
+
+impl TemperatureError {
+    /// Returns the part of the input that caused a parse failure, if any.
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            TemperatureError::MissingUnit { span }
+            | TemperatureError::InvalidUnit { span, .. }
+            | TemperatureError::InvalidNumber { span, .. } => Some(span.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemperatureError::BelowAbsoluteZero { value, unit } => {
+                let temp = Temperature::new_unchecked(*value, *unit);
+                write!(f, "{temp} is below absolute zero")
+            }
+            TemperatureError::NonFinite { value, unit } => {
+                let temp = Temperature::new_unchecked(*value, *unit);
+                write!(f, "{temp} is not finite")
+            }
+            TemperatureError::OutOfRange { value, unit } => {
+                let temp = Temperature::new_unchecked(*value, *unit);
+                write!(f, "{temp} is out of range for the value type")
+            }
+            TemperatureError::OutOfBounds {
+                value,
+                unit,
+                min,
+                max,
+            } => {
+                let temp = Temperature::new_unchecked(*value, *unit);
+                write!(f, "{temp} is outside {min} to {max}")
+            }
+            TemperatureError::OutOfDomain {
+                quantity,
+                value,
+                unit,
+                min,
+                max,
+            } => write!(
+                f,
+                "{quantity} of {value} {unit} is outside {min} to {max} {unit}"
+            ),
+            TemperatureError::InvalidUncertainty { value } => {
+                write!(f, "Uncertainty {value} is negative or not finite")
+            }
+            TemperatureError::InvertedRange { low, high } => {
+                write!(f, "{low} is hotter than {high}")
+            }
+            TemperatureError::Empty => write!(f, "Empty temperature string"),
+            TemperatureError::MissingUnit { .. } => write!(f, "Missing temperature unit"),
+            TemperatureError::InvalidUnit { unit, .. } => {
+                write!(f, "Invalid temperature unit '{unit}'")
+            }
+            TemperatureError::InvalidNumber { .. } => write!(f, "Invalid temperature number"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TemperatureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TemperatureError::InvalidNumber {
+                source: Some(source),
+                ..
+            } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TemperatureError;
+    use crate::temperature::{Temperature, TemperatureUnit};
+
+    #[test]
+    fn below_absolute_zero_records_value_and_unit() {
+        let err = Temperature::new(-1.0, TemperatureUnit::Kelvin).unwrap_err();
+        assert_eq!(
+            err,
+            TemperatureError::BelowAbsoluteZero {
+                value: -1.0,
+                unit: TemperatureUnit::Kelvin
+            }
+        );
+        assert_eq!(format!("{err}"), "-1K is below absolute zero");
+        assert!(err.span().is_none());
+    }
+
+    #[test]
+    fn parse_errors_carry_spans() {
+        let err = "  10X ".parse::<Temperature>().unwrap_err();
+        assert_eq!(err.span(), Some(4..5));
+        let err = "12.5".parse::<Temperature>().unwrap_err();
+        assert_eq!(err, TemperatureError::MissingUnit { span: 4..4 });
+        let err = " 1..5 Ra".parse::<Temperature>().unwrap_err();
+        assert_eq!(err.span(), Some(1..5));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn float_errors_are_chained() {
+        use std::error::Error;
+
+        let err = "1.2.3C".parse::<Temperature>().unwrap_err();
+        let source = err.source().expect("has a source");
+        assert_eq!(
+            format!("{source}"),
+            format!("{}", "1.2.3".parse::<f64>().unwrap_err())
+        );
+
+        let boxed: Box<dyn Error> = Box::new(err);
+        assert_eq!(format!("{boxed}"), "Invalid temperature number");
+    }
+}
@@ -0,0 +1,579 @@
+//! Exact temperature conversions using rational arithmetic.
+//!
+//! `Temperature` stores an `f64`, so `98.6F` converts to `37.00000000000001°C`.
+//! `ExactTemperature` stores a `Rational` instead. Every scale is defined by
+//! exact rationals, so conversions between them are exact, decimal input is
+//! parsed without loss, and rounding happens only when the value is
+//! formatted.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::exact::ExactTemperature;
+//! use convert_temp::TemperatureUnit;
+//!
+//! let body: ExactTemperature = "98.6F".parse().unwrap();
+//! let celsius = body.to(TemperatureUnit::Celsius).unwrap();
+//! assert_eq!(format!("{celsius}"), "37\u{00B0}C");
+//!
+//! let newton = celsius.to(TemperatureUnit::Newton).unwrap();
+//! assert_eq!(format!("{newton}"), "12.21\u{00B0}N");
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use alloc::vec::Vec;
+
+use crate::scale::{exact_affine, Ratio};
+use crate::temperature::{split_unit, Result, Temperature, TemperatureError, TemperatureUnit};
+
+/// Fractional digits shown for values whose decimal expansion doesn't end,
+/// when no precision is given.
+const DEFAULT_DIGITS: usize = 15;
+
+/// An exact rational number, always stored in lowest terms with a positive
+/// denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// Zero.
+    pub const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// Creates `numerator / denominator`, or `None` if the denominator is zero
+    /// or the value in lowest terms doesn't fit, as for `i128::MIN / -1`.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Rational> {
+        if denominator == 0 {
+            return None;
+        }
+        Rational::reduced(numerator, denominator)
+    }
+
+    /// Creates `numerator / denominator` in lowest terms, or `None` if it
+    /// doesn't fit. The denominator must be nonzero.
+    fn reduced(numerator: i128, denominator: i128) -> Option<Rational> {
+        // Reduce the magnitudes, which always fit in a u128, then apply the
+        // sign to the numerator.
+        let divisor = gcd(numerator, denominator);
+        let magnitude = numerator.unsigned_abs() / divisor;
+        let numerator = if (numerator < 0) != (denominator < 0) {
+            0i128.checked_sub_unsigned(magnitude)?
+        } else {
+            i128::try_from(magnitude).ok()?
+        };
+        let denominator = i128::try_from(denominator.unsigned_abs() / divisor).ok()?;
+        Some(Rational {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Creates a whole number.
+    pub const fn from_integer(value: i128) -> Rational {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    /// Returns the numerator, in lowest terms.
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    /// Returns the denominator, in lowest terms. Always positive.
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// Adds two rationals, or returns `None` on overflow.
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator)?
+            .checked_add(other.numerator.checked_mul(self.denominator)?)?;
+        Rational::new(numerator, self.denominator.checked_mul(other.denominator)?)
+    }
+
+    /// Subtracts two rationals, or returns `None` on overflow.
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        self.checked_add(Rational {
+            numerator: other.numerator.checked_neg()?,
+            denominator: other.denominator,
+        })
+    }
+
+    /// Multiplies two rationals, or returns `None` on overflow.
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        // Cancel across before multiplying to keep intermediates small.
+        let a = i128::try_from(gcd(self.numerator, other.denominator)).ok()?;
+        let b = i128::try_from(gcd(other.numerator, self.denominator)).ok()?;
+        Rational::new(
+            (self.numerator / a).checked_mul(other.numerator / b)?,
+            (self.denominator / b).checked_mul(other.denominator / a)?,
+        )
+    }
+
+    /// Divides two rationals, or returns `None` on overflow or division by
+    /// zero.
+    pub fn checked_div(self, other: Rational) -> Option<Rational> {
+        self.checked_mul(Rational::new(other.denominator, other.numerator)?)
+    }
+
+    /// Returns the nearest `f64`.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// Returns -1, 0 or 1 following the sign of the value.
+    fn signum(&self) -> i128 {
+        self.numerator.signum()
+    }
+
+    /// Writes the value as a decimal rounded half away from zero to
+    /// `digits` fractional digits, trimming trailing zeros if `trim` is set.
+    fn write_decimal(&self, f: &mut fmt::Formatter<'_>, digits: usize, trim: bool) -> fmt::Result {
+        let denominator = self.denominator.unsigned_abs();
+        if denominator > u128::MAX / 10 {
+            return write!(f, "{}", self.to_f64());
+        }
+
+        let mut whole = self.numerator.unsigned_abs() / denominator;
+        let mut remainder = self.numerator.unsigned_abs() % denominator;
+        let mut fraction = Vec::with_capacity(digits);
+        for _ in 0..digits {
+            remainder *= 10;
+            fraction.push((remainder / denominator) as u8);
+            remainder %= denominator;
+        }
+
+        if remainder * 2 >= denominator {
+            let mut carry = true;
+            for digit in fraction.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                whole += 1;
+            }
+        }
+
+        if trim {
+            while fraction.last() == Some(&0) {
+                fraction.pop();
+            }
+        }
+
+        let is_zero = whole == 0 && fraction.iter().all(|&digit| digit == 0);
+        if self.numerator < 0 && !is_zero {
+            write!(f, "-")?;
+        }
+        write!(f, "{whole}")?;
+        if !fraction.is_empty() {
+            write!(f, ".")?;
+            for digit in fraction {
+                write!(f, "{digit}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of fractional digits in the decimal expansion, or
+    /// `None` if it doesn't terminate.
+    fn terminating_digits(&self) -> Option<usize> {
+        let mut denominator = self.denominator;
+        let mut twos = 0;
+        let mut fives = 0;
+        while denominator % 2 == 0 {
+            denominator /= 2;
+            twos += 1;
+        }
+        while denominator % 5 == 0 {
+            denominator /= 5;
+            fives += 1;
+        }
+        (denominator == 1).then_some(twos.max(fives))
+    }
+}
+
+/// Returns the greatest common divisor of the magnitudes, which may be
+/// `2^127` and so doesn't always fit in an `i128`.
+fn gcd(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    // Only zero has a zero gcd with zero; dividing zero by one is harmless.
+    a.max(1)
+}
+
+impl From<Ratio> for Rational {
+    fn from(ratio: Ratio) -> Rational {
+        // Scale ratios are `i64`s with nonzero denominators, far inside the
+        // range of an `i128`.
+        Rational::reduced(ratio.numerator as i128, ratio.denominator as i128)
+            .expect("an i64 ratio fits in a Rational")
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        let difference = self.checked_sub(*other)?;
+        Some(difference.signum().cmp(&0))
+    }
+}
+
+/// Formats the value as a decimal. Terminating decimals are written in full;
+/// other values are rounded half away from zero to the formatter's precision,
+/// or to 15 fractional digits.
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (f.precision(), self.terminating_digits()) {
+            (Some(precision), _) => self.write_decimal(f, precision, false),
+            (None, Some(digits)) => self.write_decimal(f, digits, false),
+            (None, None) => self.write_decimal(f, DEFAULT_DIGITS, true),
+        }
+    }
+}
+
+/// Why a decimal didn't parse as a `Rational`.
+enum ParseError {
+    /// The input isn't a decimal number.
+    Invalid,
+    /// The input is a decimal number, but doesn't fit in an `i128` fraction.
+    Overflow,
+}
+
+impl Rational {
+    /// Parses a decimal number like `"98.6"`, `"-40"` or `"2.5e-3"` exactly.
+    fn parse(input: &str) -> core::result::Result<Rational, ParseError> {
+        let (mantissa, exponent) = match input.find(['e', 'E']) {
+            Some(index) => {
+                let exponent: i32 = input[index + 1..]
+                    .parse()
+                    .map_err(|_| ParseError::Invalid)?;
+                (&input[..index], exponent)
+            }
+            None => (input, 0),
+        };
+
+        let (negative, digits) = match mantissa.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(ParseError::Invalid);
+        }
+
+        let mut numerator: i128 = 0;
+        for c in whole.chars().chain(fraction.chars()) {
+            let digit = c.to_digit(10).ok_or(ParseError::Invalid)?;
+            numerator = numerator
+                .checked_mul(10)
+                .and_then(|n| n.checked_add(digit as i128))
+                .ok_or(ParseError::Overflow)?;
+        }
+        if negative {
+            numerator = -numerator;
+        }
+
+        let scale = i32::try_from(fraction.len())
+            .ok()
+            .and_then(|digits| exponent.checked_sub(digits))
+            .ok_or(ParseError::Overflow)?;
+        let power = 10i128
+            .checked_pow(scale.unsigned_abs())
+            .ok_or(ParseError::Overflow)?;
+        let value = if scale >= 0 {
+            numerator
+                .checked_mul(power)
+                .and_then(|n| Rational::new(n, 1))
+        } else {
+            Rational::new(numerator, power)
+        };
+        value.ok_or(ParseError::Overflow)
+    }
+}
+
+/// Parses a decimal number like `"98.6"`, `"-40"` or `"2.5e-3"` exactly.
+///
+/// Fails with `InvalidNumber` if the input isn't a decimal number, and with
+/// `OutOfRange` if it is one that doesn't fit in a `Rational`, such as
+/// `"1e-40"`. A bare number has no unit, so that error reports the value in
+/// kelvin; parsing an `ExactTemperature` reports its own unit.
+impl FromStr for Rational {
+    type Err = TemperatureError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Rational::parse(input).map_err(|err| parse_error(err, input, TemperatureUnit::Kelvin))
+    }
+}
+
+/// Converts a `ParseError` for `input`, read on the `unit` scale, to the
+/// crate's error.
+fn parse_error(err: ParseError, input: &str, unit: TemperatureUnit) -> TemperatureError {
+    match err {
+        ParseError::Invalid => TemperatureError::InvalidNumber {
+            span: 0..input.len(),
+            source: None,
+        },
+        ParseError::Overflow => TemperatureError::OutOfRange {
+            value: input.parse().unwrap_or(f64::NAN),
+            unit,
+        },
+    }
+}
+
+/// A temperature stored as an exact rational, paired with its unit.
+///
+/// Like `Temperature`, the fields are private so that every
+/// `ExactTemperature` has been checked by [`ExactTemperature::new`] and is
+/// not below absolute zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExactTemperature {
+    value: Rational,
+    unit: TemperatureUnit,
+}
+
+impl ExactTemperature {
+    /// Creates a new exact temperature, rejecting values below absolute zero.
+    pub fn new(value: Rational, unit: TemperatureUnit) -> Result<ExactTemperature> {
+        let scale = unit.scale();
+        let above_zero =
+            value
+                .checked_sub(scale.absolute_zero.into())
+                .ok_or(TemperatureError::OutOfRange {
+                    value: value.to_f64(),
+                    unit,
+                })?;
+        if above_zero.signum() * (scale.kelvin_per_degree.numerator.signum() as i128) < 0 {
+            Err(TemperatureError::BelowAbsoluteZero {
+                value: value.to_f64(),
+                unit,
+            })
+        } else {
+            Ok(ExactTemperature { value, unit })
+        }
+    }
+
+    /// Returns the reading, in this temperature's unit.
+    pub fn value(&self) -> Rational {
+        self.value
+    }
+
+    /// Returns the unit of the reading.
+    pub fn unit(&self) -> TemperatureUnit {
+        self.unit
+    }
+
+    /// Converts this temperature to the requested unit exactly.
+    ///
+    /// Fails with `OutOfRange` if the result doesn't fit in a `Rational`.
+    pub fn to(&self, unit: TemperatureUnit) -> Result<ExactTemperature> {
+        let (scale, offset, divisor) = exact_affine(self.unit, unit);
+        let value = self
+            .value
+            .checked_mul(Rational::from_integer(scale as i128))
+            .and_then(|value| value.checked_add(Rational::from_integer(offset as i128)))
+            .and_then(|value| value.checked_div(Rational::from_integer(divisor as i128)))
+            .ok_or(TemperatureError::OutOfRange {
+                value: self.value.to_f64(),
+                unit: self.unit,
+            })?;
+        Ok(ExactTemperature { value, unit })
+    }
+
+    /// Rounds this temperature to the nearest `f64`-backed `Temperature`.
+    ///
+    /// The result is checked again by `Temperature::new`: rounding a value
+    /// within a hair of absolute zero could otherwise leave it just below.
+    pub fn to_temperature(&self) -> Result<Temperature> {
+        Temperature::new(self.value.to_f64(), self.unit)
+    }
+}
+
+impl fmt::Display for ExactTemperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        if self.unit.scale().degree_sign {
+            write!(f, "\u{00B0}")?;
+        }
+        write!(f, "{}", self.unit.scale().symbol)
+    }
+}
+
+/// Parses an exact temperature from a string like `"98.6F"`, with the same
+/// units as `Temperature`.
+impl FromStr for ExactTemperature {
+    type Err = TemperatureError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (span, unit) = split_unit(input)?;
+        let number = &input[span.clone()];
+        let value = Rational::parse(number).map_err(|err| match err {
+            ParseError::Invalid => TemperatureError::InvalidNumber { span, source: None },
+            ParseError::Overflow => parse_error(err, number, unit),
+        })?;
+        ExactTemperature::new(value, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExactTemperature, Rational};
+    use crate::temperature::{TemperatureError, TemperatureUnit};
+
+    fn exact(input: &str) -> ExactTemperature {
+        input.parse().expect("valid")
+    }
+
+    #[test]
+    fn parses_decimals_losslessly() {
+        let value: Rational = "98.6".parse().expect("valid");
+        assert_eq!(value, Rational::new(493, 5).expect("nonzero"));
+        let value: Rational = "-2.5e-3".parse().expect("valid");
+        assert_eq!(value, Rational::new(-1, 400).expect("nonzero"));
+        let value: Rational = "1.5E2".parse().expect("valid");
+        assert_eq!(value, Rational::from_integer(150));
+        assert!("abc".parse::<Rational>().is_err());
+        assert!(".".parse::<Rational>().is_err());
+    }
+
+    #[test]
+    fn extreme_fractions_do_not_overflow() {
+        assert_eq!(Rational::new(i128::MIN, -1), None);
+        assert_eq!(
+            Rational::new(i128::MIN, i128::MIN),
+            Some(Rational::from_integer(1))
+        );
+        let min = Rational::new(i128::MIN, 1).expect("fits");
+        assert_eq!(min.numerator(), i128::MIN);
+        assert_eq!(
+            Rational::new(i128::MIN, 2).map(|r| r.numerator()),
+            Some(i128::MIN / 2)
+        );
+        assert_eq!(Rational::new(-3, -6), Rational::new(1, 2));
+        assert!(min.checked_mul(Rational::from_integer(-1)).is_none());
+        assert_eq!(
+            min.checked_div(min),
+            None,
+            "1/MIN has no positive i128 denominator"
+        );
+        assert_eq!(
+            min.checked_div(Rational::from_integer(-2))
+                .map(|r| r.numerator()),
+            Some(1 << 126)
+        );
+    }
+
+    #[test]
+    fn reports_decimals_too_large_to_store() {
+        for input in [
+            "1e-40",
+            "2.5e39",
+            "1234567890123456789012345678901234567890",
+        ] {
+            let err = input.parse::<Rational>().unwrap_err();
+            assert!(
+                matches!(err, TemperatureError::OutOfRange { .. }),
+                "{input}: {err:?}"
+            );
+        }
+        assert!(matches!(
+            "1.5e-2147483648".parse::<Rational>(),
+            Err(TemperatureError::OutOfRange { .. })
+        ));
+
+        let err = "1e-40C".parse::<ExactTemperature>().unwrap_err();
+        assert_eq!(
+            err,
+            TemperatureError::OutOfRange {
+                value: 1e-40,
+                unit: TemperatureUnit::Celsius
+            }
+        );
+        let err = "1e-4xC".parse::<ExactTemperature>().unwrap_err();
+        assert_eq!(err.span(), Some(0..5));
+    }
+
+    #[test]
+    fn conversion_overflow_is_an_error() {
+        let huge = ExactTemperature::new(
+            Rational::from_integer(i128::MAX / 2),
+            TemperatureUnit::Kelvin,
+        )
+        .expect("valid");
+        let err = huge.to(TemperatureUnit::Fahrenheit).unwrap_err();
+        assert!(matches!(
+            err,
+            TemperatureError::OutOfRange {
+                unit: TemperatureUnit::Kelvin,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn conversions_are_exact() {
+        let celsius = exact("98.6F").to(TemperatureUnit::Celsius).expect("fits");
+        assert_eq!(celsius.value(), Rational::from_integer(37));
+
+        let kelvin = exact("-459.67F").to(TemperatureUnit::Kelvin).expect("fits");
+        assert_eq!(kelvin.value(), Rational::ZERO);
+
+        let romer = exact("100C").to(TemperatureUnit::Romer).expect("fits");
+        assert_eq!(romer.value(), Rational::from_integer(60));
+    }
+
+    #[test]
+    fn round_trip_through_every_unit_is_exact() {
+        let start = exact("36.6C");
+        for unit in TemperatureUnit::ALL {
+            let there = start.to(unit).expect("fits");
+            let back = there.to(TemperatureUnit::Celsius).expect("fits");
+            assert_eq!(back, start, "{unit:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_below_absolute_zero() {
+        let err = "-273.16C".parse::<ExactTemperature>().unwrap_err();
+        assert!(matches!(err, TemperatureError::BelowAbsoluteZero { .. }));
+        assert!("559.726De".parse::<ExactTemperature>().is_err());
+        assert!("559.725De".parse::<ExactTemperature>().is_ok());
+    }
+
+    #[test]
+    fn rounds_only_when_formatting() {
+        let celsius = exact("100F").to(TemperatureUnit::Celsius).expect("fits");
+        assert_eq!(celsius.value(), Rational::new(340, 9).expect("nonzero"));
+        assert_eq!(format!("{celsius}"), "37.777777777777778\u{00B0}C");
+        assert_eq!(format!("{celsius:.2}"), "37.78\u{00B0}C");
+        assert_eq!(format!("{:.3}", exact("-0.0004C").value()), "0.000");
+        assert_eq!(format!("{:.1}", exact("-0.05C").value()), "-0.1");
+        assert_eq!(format!("{:.0}", exact("9.5C").value()), "10");
+    }
+
+    #[test]
+    fn converts_to_f64_temperature() {
+        let temp = exact("37.5C").to_temperature().expect("valid");
+        assert_eq!(temp.value(), 37.5);
+        assert_eq!(temp.unit(), TemperatureUnit::Celsius);
+
+        let zero = exact("-459.67F").to_temperature().expect("valid");
+        assert_eq!(zero.to(TemperatureUnit::Kelvin).value(), 0.0);
+    }
+}
@@ -0,0 +1,286 @@
+//! Formatting options for temperatures.
+//!
+//! `Display` for `Temperature` honours the standard formatter flags:
+//! precision rounds the value, `+` forces a sign, and width, fill and
+//! alignment pad the whole reading, unit included. Readings are
+//! right-aligned by default, like numbers. The alternate flag writes the long
+//! form, such as `37.5 degrees Celsius`.
+//!
+//! There is no formatter flag for significant figures, so
+//! [`Temperature::significant`] returns a wrapper that rounds to them.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! let t = Temperature::new(37.46, TemperatureUnit::Celsius).unwrap();
+//! assert_eq!(format!("{t:.1}"), "37.5\u{00B0}C");
+//! assert_eq!(format!("[{t:>10.1}]"), "[    37.5\u{00B0}C]");
+//! assert_eq!(format!("{t:+#.1}"), "+37.5 degrees Celsius");
+//! assert_eq!(format!("{}", t.significant(3)), "37.5\u{00B0}C");
+//! ```
+
+use core::fmt::{self, Write};
+
+use crate::numeric::Scalar;
+use crate::temperature::{Temperature, TemperatureUnit};
+
+/// Counts the characters written, to work out padding.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// A small fixed-size buffer, for formatting without allocating.
+struct StackBuf {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl StackBuf {
+    fn as_str(&self) -> &str {
+        // Only whole `&str`s are ever copied in.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for StackBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes whatever `content` writes, padded to the formatter's width with
+/// its fill and alignment. Right alignment is the default.
+pub(crate) fn pad(
+    f: &mut fmt::Formatter<'_>,
+    content: impl Fn(&mut dyn Write) -> fmt::Result,
+) -> fmt::Result {
+    let Some(width) = f.width() else {
+        return content(f);
+    };
+    let mut count = CharCount(0);
+    content(&mut count)?;
+
+    let padding = width.saturating_sub(count.0);
+    let before = match f.align() {
+        Some(fmt::Alignment::Left) => 0,
+        Some(fmt::Alignment::Center) => padding / 2,
+        Some(fmt::Alignment::Right) | None => padding,
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    content(f)?;
+    for _ in before..padding {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Writes `+` if the formatter asks for a sign and `value` has none.
+pub(crate) fn write_plus(w: &mut dyn Write, sign_plus: bool, value: f64) -> fmt::Result {
+    if sign_plus && !value.is_sign_negative() {
+        w.write_char('+')?;
+    }
+    Ok(())
+}
+
+/// Writes the unit after a value: `°C` or `K`, or in the long form
+/// ` degrees Celsius` or ` kelvins`.
+pub(crate) fn write_unit(
+    w: &mut dyn Write,
+    unit: TemperatureUnit,
+    long: bool,
+    singular: bool,
+) -> fmt::Result {
+    let scale = unit.scale();
+    if !long {
+        if scale.degree_sign {
+            w.write_char('\u{00B0}')?;
+        }
+        return w.write_str(scale.symbol);
+    }
+
+    if unit == TemperatureUnit::Kelvin {
+        w.write_str(if singular { " kelvin" } else { " kelvins" })
+    } else {
+        let degrees = if singular { "degree" } else { "degrees" };
+        write!(w, " {degrees} {}", scale.name)
+    }
+}
+
+/// Whether the long form of a value takes a singular unit, as in
+/// `1 degree Celsius`. `1.0 degrees` stays plural.
+pub(crate) fn is_singular(value: f64, precision: Option<usize>) -> bool {
+    value.abs() == 1.0 && precision.unwrap_or(0) == 0
+}
+
+/// Writes `value` rounded to `digits` significant figures, in positional
+/// notation. Trailing zeros are kept, since they are significant.
+fn write_significant(w: &mut dyn Write, value: f64, digits: usize) -> fmt::Result {
+    // f64 has at most 17 significant decimal digits.
+    let digits = digits.clamp(1, 17);
+    let mut scientific = StackBuf {
+        bytes: [0; 32],
+        len: 0,
+    };
+    write!(scientific, "{:.*e}", digits - 1, value.abs())?;
+    let (mantissa, exponent) = scientific.as_str().split_once('e').ok_or(fmt::Error)?;
+    let exponent: i32 = exponent.parse().map_err(|_| fmt::Error)?;
+    let (first, rest) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    if value.is_sign_negative() && value != 0.0 {
+        w.write_char('-')?;
+    }
+    let significand = || first.chars().chain(rest.chars());
+    if exponent < 0 {
+        w.write_str("0.")?;
+        for _ in 1..exponent.unsigned_abs() {
+            w.write_char('0')?;
+        }
+        for c in significand() {
+            w.write_char(c)?;
+        }
+        return Ok(());
+    }
+
+    let whole = exponent as usize + 1;
+    for (i, c) in significand().enumerate() {
+        if i == whole {
+            w.write_char('.')?;
+        }
+        w.write_char(c)?;
+    }
+    for _ in digits..whole {
+        w.write_char('0')?;
+    }
+    Ok(())
+}
+
+/// A temperature rounded to significant figures, returned by
+/// [`Temperature::significant`].
+///
+/// Honours the same width, fill, alignment, sign and alternate flags as
+/// `Temperature`; precision is ignored.
+#[derive(Debug, Clone, Copy)]
+pub struct Significant<'a, T> {
+    temp: &'a Temperature<T>,
+    digits: usize,
+}
+
+impl<T: Scalar> fmt::Display for Significant<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.temp.value().to_f64();
+        let sign_plus = f.sign_plus();
+        let long = f.alternate();
+        let singular = is_singular(value, None) && self.digits <= 1;
+        pad(f, |w| {
+            write_plus(w, sign_plus, value)?;
+            write_significant(w, value, self.digits)?;
+            write_unit(w, self.temp.unit(), long, singular)
+        })
+    }
+}
+
+impl<T: Scalar> Temperature<T> {
+    /// Formats this temperature rounded to `digits` significant figures,
+    /// like `37.5°C` for three.
+    ///
+    /// The digits are those of the value as an `f64`, between 1 and 17;
+    /// other counts are clamped to that range.
+    pub fn significant(&self, digits: usize) -> Significant<'_, T> {
+        Significant { temp: self, digits }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numeric::Centi;
+    use crate::temperature::{Temperature, TemperatureUnit};
+
+    fn celsius(value: f64) -> Temperature {
+        Temperature::new(value, TemperatureUnit::Celsius).expect("valid")
+    }
+
+    #[test]
+    fn display_honours_flags() {
+        let t = celsius(37.46);
+        assert_eq!(format!("{t:.1}"), "37.5\u{00B0}C");
+        assert_eq!(format!("{t:.0}"), "37\u{00B0}C");
+        assert_eq!(format!("{t:+}"), "+37.46\u{00B0}C");
+        assert_eq!(format!("{:+}", celsius(-4.0)), "-4\u{00B0}C");
+        assert_eq!(format!("{t:9.1}|"), "   37.5\u{00B0}C|");
+        assert_eq!(format!("{t:<9.1}|"), "37.5\u{00B0}C   |");
+        assert_eq!(format!("{t:*^10.1}"), "**37.5\u{00B0}C**");
+        assert_eq!(format!("{t:2}"), "37.46\u{00B0}C");
+
+        let k = Temperature::new(300.0, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(format!("{k:>6}"), "  300K");
+    }
+
+    #[test]
+    fn fixed_point_precision_rounds() {
+        let t = Temperature::new(Centi::from_raw(3_746), TemperatureUnit::Celsius).expect("valid");
+        assert_eq!(format!("{t}"), "37.46\u{00B0}C");
+        assert_eq!(format!("{t:.1}"), "37.5\u{00B0}C");
+        assert_eq!(format!("{t:.4}"), "37.4600\u{00B0}C");
+        assert_eq!(format!("{t:+.0}"), "+37\u{00B0}C");
+        let t = Temperature::new(Centi::from_raw(-1_050), TemperatureUnit::Celsius).expect("valid");
+        assert_eq!(format!("{t:.0}"), "-11\u{00B0}C");
+    }
+
+    #[test]
+    fn alternate_writes_long_form() {
+        assert_eq!(format!("{:#}", celsius(37.5)), "37.5 degrees Celsius");
+        assert_eq!(format!("{:#}", celsius(1.0)), "1 degree Celsius");
+        assert_eq!(format!("{:#.1}", celsius(1.0)), "1.0 degrees Celsius");
+        let k = Temperature::new(300.0, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(format!("{k:#}"), "300 kelvins");
+        let k = Temperature::new(1.0, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(format!("{k:#}"), "1 kelvin");
+
+        for unit in TemperatureUnit::ALL {
+            let t = celsius(21.5).to(unit);
+            let parsed: Temperature = format!("{t:#}").parse().expect("round trip");
+            assert_eq!(parsed.unit(), unit);
+            assert_eq!(parsed.value(), t.value());
+        }
+    }
+
+    #[test]
+    fn significant_figures() {
+        assert_eq!(
+            format!("{}", celsius(37.46).significant(3)),
+            "37.5\u{00B0}C"
+        );
+        assert_eq!(format!("{}", celsius(37.0).significant(3)), "37.0\u{00B0}C");
+        assert_eq!(
+            format!("{}", celsius(1234.5).significant(2)),
+            "1200\u{00B0}C"
+        );
+        assert_eq!(
+            format!("{}", celsius(0.012_34).significant(2)),
+            "0.012\u{00B0}C"
+        );
+        assert_eq!(format!("{}", celsius(-40.0).significant(1)), "-40\u{00B0}C");
+        assert_eq!(format!("{}", celsius(0.0).significant(2)), "0.0\u{00B0}C");
+        assert_eq!(format!("{}", celsius(99.96).significant(3)), "100\u{00B0}C");
+
+        let t = Temperature::new(310.65, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(format!("{:>8}", t.significant(3)), "    311K");
+        let t = Temperature::new(310.27, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(format!("{:+#}", t.significant(4)), "+310.3 kelvins");
+    }
+}
@@ -0,0 +1,56 @@
+//! `convert-temp` library.
+//!
+//! Convert temperatures between Celsius, Fahrenheit, Kelvin and the Rankine,
+//! Réaumur, Delisle, Newton and Rømer scales. The `convert-temp`
+//! command-line tool is a thin wrapper around this crate.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::{Temperature, TemperatureUnit, BOILING_POINT};
+//!
+//! let boiling_point_fahrenheit = BOILING_POINT.to(TemperatureUnit::Fahrenheit);
+//! assert_eq!(format!("{boiling_point_fahrenheit}"), "212\u{00B0}F");
+//!
+//! let t: Temperature = "37.5C".parse().unwrap();
+//! assert_eq!(t.unit(), TemperatureUnit::Celsius);
+//! ```
+//!
+//! This was a learning exercise. If you need a way to handle temperature
+//! and other measurments in Rust, look at the measurements crate (https://docs.rs/measurements/latest/measurements/#).
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod atmosphere;
+mod codes;
+pub mod delta;
+pub mod error;
+#[cfg(feature = "alloc")]
+pub mod exact;
+pub mod format;
+#[cfg(feature = "alloc")]
+pub mod locale;
+#[cfg(feature = "std")]
+pub mod measurement;
+pub mod numeric;
+pub mod range;
+pub mod reference;
+mod scale;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod temperature;
+#[cfg(feature = "std")]
+pub mod thermocouple;
+pub mod typed;
+pub mod validate;
+#[cfg(feature = "std")]
+pub mod water;
+
+pub use crate::delta::TemperatureDelta;
+pub use crate::error::{Result, TemperatureError};
+pub use crate::temperature::{
+    Temperature, TemperatureUnit, ABSOLUTE_ZERO, BOILING_POINT, FREEZING_POINT,
+};
@@ -0,0 +1,537 @@
+//! Locale-aware parsing and formatting.
+//!
+//! `FromStr` and `Display` only know `37.5°C`. A `Locale` adds a language
+//! and region's decimal and grouping separators, its spacing before the
+//! unit, and its words for "degree" and the scales, so `"37,5 °C"` and
+//! `"98,6 Grad Fahrenheit"` parse for a German user, and
+//! `Temperature::localized` writes temperatures back the same way. The
+//! locale is always passed explicitly; the command-line tool builds one from
+//! the system locale.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::locale::Locale;
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! let german = Locale::new("de-DE");
+//! let fever: Temperature = Temperature::parse_locale("38,5 Grad Celsius", &german).unwrap();
+//! assert_eq!(fever.value(), 38.5);
+//!
+//! let spanish = Locale::new("es_ES.UTF-8");
+//! let oven: Temperature = Temperature::parse_locale("1.200,5 grados Fahrenheit", &spanish).unwrap();
+//! assert_eq!(oven.value(), 1200.5);
+//! assert_eq!(oven.unit(), TemperatureUnit::Fahrenheit);
+//!
+//! let french = Locale::new("fr-FR");
+//! assert_eq!(format!("{}", fever.localized(&french)), "38,5\u{00A0}\u{00B0}C");
+//! assert_eq!(format!("{:#}", fever.localized(&french)), "38,5 degr\u{00E9}s Celsius");
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+
+use crate::format;
+use crate::numeric::Scalar;
+use crate::temperature::{split_unit_with, Result, Temperature, TemperatureError, TemperatureUnit};
+use crate::validate::Validator;
+
+/// A language with its own words for temperatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Language {
+    English,
+    German,
+    French,
+    Spanish,
+    Italian,
+    Portuguese,
+    Dutch,
+}
+
+impl Language {
+    /// Looks up a language by its ISO 639-1 code, ignoring case.
+    fn from_code(code: &str) -> Option<Language> {
+        let languages = [
+            ("en", Language::English),
+            ("de", Language::German),
+            ("fr", Language::French),
+            ("es", Language::Spanish),
+            ("it", Language::Italian),
+            ("pt", Language::Portuguese),
+            ("nl", Language::Dutch),
+        ];
+        languages
+            .into_iter()
+            .find(|(tag, _)| tag.eq_ignore_ascii_case(code))
+            .map(|(_, language)| language)
+    }
+
+    /// Words for "degree", singular and plural, with and without accents.
+    fn degree_words(self) -> &'static [&'static str] {
+        match self {
+            Language::English => &[],
+            Language::German => &["Grad"],
+            Language::French => &["degr\u{00E9}s", "degr\u{00E9}", "degres", "degre"],
+            Language::Spanish => &["grados", "grado"],
+            Language::Italian => &["gradi", "grado"],
+            Language::Portuguese => &["graus", "grau"],
+            Language::Dutch => &["graden", "graad"],
+        }
+    }
+
+    /// The words for one degree and for several.
+    fn degree_forms(self) -> (&'static str, &'static str) {
+        match self {
+            Language::English => ("degree", "degrees"),
+            Language::German => ("Grad", "Grad"),
+            Language::French => ("degr\u{00E9}", "degr\u{00E9}s"),
+            Language::Spanish => ("grado", "grados"),
+            Language::Italian => ("grado", "gradi"),
+            Language::Portuguese => ("grau", "graus"),
+            Language::Dutch => ("graad", "graden"),
+        }
+    }
+
+    /// The words for one kelvin and for several.
+    fn kelvin_forms(self) -> (&'static str, &'static str) {
+        match self {
+            Language::German => ("Kelvin", "Kelvin"),
+            Language::Italian | Language::Dutch => ("kelvin", "kelvin"),
+            _ => ("kelvin", "kelvins"),
+        }
+    }
+
+    /// Returns true if a count of `magnitude` takes the singular. French and
+    /// Portuguese use it below two, the others only for exactly one.
+    fn is_singular(self, magnitude: f64) -> bool {
+        match self {
+            Language::French | Language::Portuguese => magnitude < 2.0,
+            _ => magnitude == 1.0,
+        }
+    }
+
+    /// Unit names beyond the English ones `TemperatureUnit::from_name` knows.
+    fn unit_names(self) -> &'static [(&'static str, TemperatureUnit)] {
+        match self {
+            Language::English | Language::German | Language::Dutch => &[],
+            Language::French => &[("centigrades", TemperatureUnit::Celsius)],
+            Language::Spanish | Language::Portuguese => &[
+                ("cent\u{00ED}grados", TemperatureUnit::Celsius),
+                ("centigrados", TemperatureUnit::Celsius),
+                ("cent\u{00ED}grado", TemperatureUnit::Celsius),
+                ("centigrado", TemperatureUnit::Celsius),
+            ],
+            Language::Italian => &[
+                ("centigradi", TemperatureUnit::Celsius),
+                ("centigrado", TemperatureUnit::Celsius),
+            ],
+        }
+    }
+}
+
+/// The number and unit conventions of a language and region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    language: Language,
+    decimal: char,
+    grouping: char,
+    /// The fewest digits the leading group needs before thousands are
+    /// separated: Spanish writes `1234` but `12.345`.
+    min_grouping: usize,
+    /// Whether a no-break space separates the number from the unit.
+    unit_space: bool,
+}
+
+impl Locale {
+    /// Creates a locale from a BCP 47 tag like `"de-CH"` or a POSIX name
+    /// like `"fr_FR.UTF-8"`.
+    ///
+    /// Unsupported languages use English words; unknown regions use the
+    /// language's usual separators, and unknown tags fall back to `37.5`
+    /// with `,` grouping.
+    pub fn new(tag: &str) -> Locale {
+        let tag = tag.split(['.', '@']).next().unwrap_or(tag);
+        let mut parts = tag.split(['-', '_']);
+        let language = parts.next().and_then(Language::from_code);
+        let region =
+            parts.find(|part| part.len() == 2 && part.chars().all(|c| c.is_ascii_alphabetic()));
+        let region_is = |code: &str| region.is_some_and(|region| region.eq_ignore_ascii_case(code));
+
+        // Separators from CLDR, for the common regions.
+        let (decimal, grouping) = match language {
+            Some(Language::German | Language::French | Language::Italian) if region_is("CH") => {
+                ('.', '\u{2019}')
+            }
+            Some(Language::French) => (',', '\u{202F}'),
+            Some(Language::Spanish) if region_is("MX") || region_is("US") => ('.', ','),
+            Some(Language::Portuguese) if region_is("PT") => (',', '\u{00A0}'),
+            Some(
+                Language::German
+                | Language::Spanish
+                | Language::Italian
+                | Language::Portuguese
+                | Language::Dutch,
+            ) => (',', '.'),
+            Some(Language::English) | None => ('.', ','),
+        };
+
+        let language = language.unwrap_or(Language::English);
+        Locale {
+            language,
+            decimal,
+            grouping,
+            min_grouping: if language == Language::Spanish { 2 } else { 1 },
+            unit_space: language != Language::English,
+        }
+    }
+
+    /// Returns the character between the whole and fractional digits.
+    pub fn decimal_separator(&self) -> char {
+        self.decimal
+    }
+
+    /// Returns the character between groups of thousands.
+    pub fn grouping_separator(&self) -> char {
+        self.grouping
+    }
+
+    /// Returns true if `c` separates thousands in this locale. Any space
+    /// counts when the locale groups with a space.
+    fn is_grouping(&self, c: char) -> bool {
+        let spaces = ['\u{0020}', '\u{00A0}', '\u{202F}'];
+        c == self.grouping || (spaces.contains(&self.grouping) && spaces.contains(&c))
+    }
+
+    /// Rewrites a plain number like `-1234.5` with this locale's
+    /// separators.
+    fn localize(&self, number: &str) -> String {
+        let (sign, digits) = match number.strip_prefix(['-', '+']) {
+            Some(rest) => (&number[..1], rest),
+            None => ("", number),
+        };
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits, None),
+        };
+
+        let mut localized = String::with_capacity(number.len() + whole.len() / 3 * 3);
+        localized.push_str(sign);
+        let grouped = whole.len() >= 3 + self.min_grouping;
+        for (index, digit) in whole.chars().enumerate() {
+            let remaining = whole.len() - index;
+            if grouped && index > 0 && remaining % 3 == 0 {
+                localized.push(self.grouping);
+            }
+            localized.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            localized.push(self.decimal);
+            localized.push_str(fraction);
+        }
+        localized
+    }
+
+    /// Rewrites a localized number as `-1234.5`, or returns `None` if its
+    /// thousands aren't grouped in threes.
+    fn normalize(&self, number: &str) -> Option<String> {
+        let mut normalized = String::with_capacity(number.len());
+        let digits = match number.strip_prefix(['-', '\u{2212}']) {
+            Some(rest) => {
+                normalized.push('-');
+                rest
+            }
+            None => number.strip_prefix('+').unwrap_or(number),
+        };
+
+        let (whole, fraction) = match digits.split_once(self.decimal) {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits, None),
+        };
+        let mut groups = whole.split(|c| self.is_grouping(c));
+        let first = groups.next().unwrap_or("");
+        normalized.push_str(first);
+        for group in groups {
+            let grouped = (1..=3).contains(&first.len())
+                && group.len() == 3
+                && group.chars().all(|c| c.is_ascii_digit());
+            if !grouped {
+                return None;
+            }
+            normalized.push_str(group);
+        }
+
+        if let Some(fraction) = fraction {
+            normalized.push('.');
+            normalized.push_str(fraction);
+        }
+        Some(normalized)
+    }
+}
+
+/// A temperature formatted for a locale, returned by
+/// [`Temperature::localized`].
+///
+/// `{}` writes the number and symbol, like `37,5 °C`; `{:#}` writes the
+/// long name, like `37,5 degrés Celsius`.
+#[derive(Debug, Clone, Copy)]
+pub struct Localized<'a, T> {
+    temp: &'a Temperature<T>,
+    locale: &'a Locale,
+}
+
+impl<T: Scalar> fmt::Display for Localized<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let locale = self.locale;
+        let value = self.temp.value();
+        let unit = self.temp.unit();
+        let mut plain = String::new();
+        format::write_plus(&mut plain, f.sign_plus(), value.to_f64())?;
+        value.fmt_value(&mut plain, f.precision())?;
+        let number = locale.localize(&plain);
+
+        let long = f.alternate();
+        format::pad(f, |w| {
+            if long {
+                let language = locale.language;
+                let singular = language.is_singular(value.to_f64().abs());
+                let pick = |(one, many)| if singular { one } else { many };
+                if unit == TemperatureUnit::Kelvin {
+                    write!(w, "{number} {}", pick(language.kelvin_forms()))
+                } else {
+                    let degrees = pick(language.degree_forms());
+                    write!(w, "{number} {degrees} {}", unit.scale().name)
+                }
+            } else {
+                let space = if locale.unit_space { "\u{00A0}" } else { "" };
+                let degree = if unit.scale().degree_sign {
+                    "\u{00B0}"
+                } else {
+                    ""
+                };
+                write!(w, "{number}{space}{degree}{}", unit.scale().symbol)
+            }
+        })
+    }
+}
+
+impl<T: Scalar> Temperature<T> {
+    /// Formats this temperature for `locale`.
+    pub fn localized<'a>(&'a self, locale: &'a Locale) -> Localized<'a, T> {
+        Localized { temp: self, locale }
+    }
+
+    /// Parses a temperature written for `locale`, like `"37,5 °C"` or
+    /// `"98,6 Grad Fahrenheit"`, rejecting values below absolute zero.
+    ///
+    /// Everything `FromStr` accepts is accepted as well, as long as the
+    /// number uses the locale's separators.
+    pub fn parse_locale(input: &str, locale: &Locale) -> Result<Temperature<T>> {
+        let (value, unit) = parse_locale_parts(input, locale)?;
+        Temperature::new(value, unit)
+    }
+
+    /// Like [`Temperature::parse_locale`], rejecting values that `validator`
+    /// doesn't accept.
+    pub fn parse_locale_with(
+        input: &str,
+        locale: &Locale,
+        validator: impl Validator,
+    ) -> Result<Temperature<T>> {
+        let (value, unit) = parse_locale_parts(input, locale)?;
+        Temperature::new_with(value, unit, validator)
+    }
+}
+
+/// Parses a temperature in `locale` into its value and unit, unvalidated.
+fn parse_locale_parts<T: Scalar>(input: &str, locale: &Locale) -> Result<(T, TemperatureUnit)> {
+    let language = locale.language;
+    let (span, unit) = split_unit_with(input, language.degree_words(), language.unit_names())?;
+
+    let invalid = |source| TemperatureError::InvalidNumber {
+        span: span.clone(),
+        source,
+    };
+    let number = locale
+        .normalize(&input[span.clone()])
+        .ok_or_else(|| invalid(None))?;
+    let value = T::parse_value(&number).map_err(invalid)?;
+    Ok((value, unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locale;
+    use crate::numeric::Milli;
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+
+    fn parse(input: &str, tag: &str) -> Temperature {
+        Temperature::parse_locale(input, &Locale::new(tag)).expect(input)
+    }
+
+    #[test]
+    fn separators_follow_the_region() {
+        let cases = [
+            ("en-US", '.', ','),
+            ("de_DE.UTF-8", ',', '.'),
+            ("de-CH", '.', '\u{2019}'),
+            ("fr-FR", ',', '\u{202F}'),
+            ("es-MX", '.', ','),
+            ("pt-PT", ',', '\u{00A0}'),
+            ("pt-BR", ',', '.'),
+            ("ja-JP", '.', ','),
+            ("C", '.', ','),
+        ];
+        for (tag, decimal, grouping) in cases {
+            let locale = Locale::new(tag);
+            assert_eq!(locale.decimal_separator(), decimal, "{tag}");
+            assert_eq!(locale.grouping_separator(), grouping, "{tag}");
+        }
+    }
+
+    #[test]
+    fn parses_localized_numbers() {
+        assert_eq!(parse("37,5 \u{00B0}C", "de-DE").value(), 37.5);
+        assert_eq!(parse("1.234,5K", "nl-NL").value(), 1234.5);
+        assert_eq!(parse("-1.234,5 De", "nl-NL").value(), -1234.5);
+        assert_eq!(parse("1\u{202F}000,25 K", "fr-FR").value(), 1000.25);
+        assert_eq!(parse("1 000,25 K", "fr-FR").value(), 1000.25);
+        assert_eq!(parse("1\u{2019}000.5 K", "de-CH").value(), 1000.5);
+        assert_eq!(parse("\u{2212}40 F", "en-GB").value(), -40.0);
+        assert_eq!(parse("98.6F", "en-US").value(), 98.6);
+
+        let milli = Temperature::<Milli>::parse_locale("36,625 C", &Locale::new("it-IT"));
+        assert_eq!(milli.expect("valid").value().raw(), 36_625);
+    }
+
+    #[test]
+    fn parses_localized_unit_words() {
+        let cases = [
+            ("37,5 grados Celsius", "es-ES", TemperatureUnit::Celsius),
+            (
+                "37,5 grados cent\u{00ED}grados",
+                "es-ES",
+                TemperatureUnit::Celsius,
+            ),
+            ("98,6 Grad Fahrenheit", "de-DE", TemperatureUnit::Fahrenheit),
+            (
+                "37,5 degr\u{00E9}s Celsius",
+                "fr-FR",
+                TemperatureUnit::Celsius,
+            ),
+            ("37,5 gradi centigradi", "it-IT", TemperatureUnit::Celsius),
+            ("37,5 graus Celsius", "pt-BR", TemperatureUnit::Celsius),
+            ("37,5 graden Celsius", "nl-NL", TemperatureUnit::Celsius),
+            ("310,65 Kelvin", "de-AT", TemperatureUnit::Kelvin),
+        ];
+        for (input, tag, unit) in cases {
+            let temp = parse(input, tag);
+            assert_eq!(temp.unit(), unit, "{input}");
+        }
+    }
+
+    #[test]
+    fn rejects_ambiguous_numbers() {
+        let english = Locale::new("en-US");
+        let err = Temperature::<f64>::parse_locale("37,5 C", &english).unwrap_err();
+        assert_eq!(
+            err,
+            TemperatureError::InvalidNumber {
+                span: 0..4,
+                source: None
+            }
+        );
+        assert!(Temperature::<f64>::parse_locale("1,00,000 C", &english).is_err());
+        assert!(Temperature::<f64>::parse_locale("37.5 C", &Locale::new("de-DE")).is_err());
+        assert!(Temperature::<f64>::parse_locale("37,5 grados C", &english).is_err());
+    }
+
+    #[test]
+    fn formats_with_locale_conventions() {
+        let temp = parse("1234.5 C", "en-US");
+        let cases = [
+            ("en-US", "1,234.5\u{00B0}C"),
+            ("de-DE", "1.234,5\u{00A0}\u{00B0}C"),
+            ("fr-FR", "1\u{202F}234,5\u{00A0}\u{00B0}C"),
+            ("de-CH", "1\u{2019}234.5\u{00A0}\u{00B0}C"),
+            ("es-ES", "1234,5\u{00A0}\u{00B0}C"),
+        ];
+        for (tag, expected) in cases {
+            assert_eq!(
+                format!("{}", temp.localized(&Locale::new(tag))),
+                expected,
+                "{tag}"
+            );
+        }
+
+        let spanish = Locale::new("es-ES");
+        let hot = parse("-12345.5 De", "en-US");
+        assert_eq!(
+            format!("{}", hot.localized(&spanish)),
+            "-12.345,5\u{00A0}\u{00B0}De"
+        );
+        let cold = parse("300 K", "en-US");
+        assert_eq!(
+            format!("{}", cold.localized(&Locale::new("de-DE"))),
+            "300\u{00A0}K"
+        );
+        assert_eq!(format!("{}", cold.localized(&Locale::new("en-GB"))), "300K");
+    }
+
+    #[test]
+    fn formats_long_unit_names() {
+        let cases = [
+            ("37.5 C", "en-US", "37.5 degrees Celsius"),
+            ("1 F", "en-US", "1 degree Fahrenheit"),
+            ("1.5 C", "fr-FR", "1,5 degr\u{00E9} Celsius"),
+            ("37.5 C", "de-DE", "37,5 Grad Celsius"),
+            ("80 Re", "es-ES", "80 grados R\u{00E9}aumur"),
+            ("300 K", "en-US", "300 kelvins"),
+            ("300 K", "de-DE", "300 Kelvin"),
+        ];
+        for (input, tag, expected) in cases {
+            let temp = parse(input, "en-US");
+            assert_eq!(
+                format!("{:#}", temp.localized(&Locale::new(tag))),
+                expected,
+                "{tag}"
+            );
+        }
+    }
+
+    #[test]
+    fn localized_honours_flags() {
+        let german = Locale::new("de-DE");
+        let temp = parse("1234.56 C", "en-US");
+        assert_eq!(
+            format!("{:.1}", temp.localized(&german)),
+            "1.234,6\u{00A0}\u{00B0}C"
+        );
+        assert_eq!(
+            format!("{:+.0}", temp.localized(&german)),
+            "+1.235\u{00A0}\u{00B0}C"
+        );
+        assert_eq!(
+            format!("{:>12.0}|", temp.localized(&german)),
+            "    1.235\u{00A0}\u{00B0}C|"
+        );
+    }
+
+    #[test]
+    fn localized_output_parses_back() {
+        for tag in [
+            "en-US", "de-DE", "fr-FR", "es-ES", "it-IT", "pt-BR", "nl-NL", "de-CH",
+        ] {
+            let locale = Locale::new(tag);
+            for unit in TemperatureUnit::ALL {
+                let temp = parse("12345.25 K", "en-US").to(unit);
+                for text in [
+                    format!("{}", temp.localized(&locale)),
+                    format!("{:#}", temp.localized(&locale)),
+                ] {
+                    let parsed = Temperature::<f64>::parse_locale(&text, &locale).expect(&text);
+                    assert_eq!(parsed.value(), temp.value(), "{text}");
+                    assert_eq!(parsed.unit(), unit, "{text}");
+                }
+            }
+        }
+    }
+}
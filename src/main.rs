@@ -1,27 +1,37 @@
 //! `convert-temp` command-line tool.
 //!
-//! Convert temperatures between Celsius, Fahrenheit, and Kelvin.
+//! Convert temperatures between Celsius, Fahrenheit, Kelvin, Rankine,
+//! Réaumur, Delisle, Newton and Rømer.
 //!
 //! # Usage
 //! ```text
 //! convert-temp <value> <from_unit> <to_unit>
 //! convert-temp <value> <from_unit>
+//! convert-temp <reference> [<to_unit>]
 //! ```
 //!
-//! Units are `C`, `F`, or `K`.
+//! Units are `C`, `F`, `K`, `R` (Rankine), `Re` (Réaumur), `De` (Delisle),
+//! `N` (Newton) or `Ro` (Rømer).
+//!
+//! A reference temperature from the library's catalog, such as
+//! `"triple point of water"` or `LN2`, may stand in for the value and unit.
+//!
+//! The value and unit are read and written in the system locale, so a German
+//! user can write `convert-temp 37,5 "Grad Celsius" F` and see `99,5 °F`.
 //!
 //! # Examples
 //! ```text
 //! convert-temp 37.5 C F
 //! convert-temp 100 C
 //! convert-temp 273.15 K C
+//! convert-temp "triple point of water" F
 //! ```
 //!
 //! # Flags
 //! - `-h`, `--help` show usage
 //! - `-V`, `--version` show version
 //!
-//! This tool is a learning exercise; see `temperature` module for the core API.
+//! This tool is a learning exercise; see the `convert_temp` library crate for the core API.
 // Exercises from 'The Rust Programming Language'
 // https://doc.rust-lang.org/book/ch03-05-control-flow.html#summary
 //
@@ -56,21 +66,27 @@
 // temp_fahrenheit = to_fahrenheit(temp_celsius)
 // converted_temp = convert_temp(temp) temp: 37.5C or 99.5F
 
+use convert_temp::locale::Locale;
+use convert_temp::reference;
+use convert_temp::temperature::Temperature;
+use convert_temp::temperature::TemperatureUnit;
+use convert_temp::TemperatureError;
 use std::env;
-use crate::temperature::Temperature;
-use crate::temperature::TemperatureUnit;
-// use crate::temperature::{ABSOLUTE_ZERO, BOILING_POINT, FREEZING_POINT};
 
 use sys_locale::get_locale;
 
-pub mod temperature;
+const UNITS_HELP: &str =
+    "Units: C, F, K, R (Rankine), Re (R\u{00E9}aumur), De (Delisle), N (Newton), Ro (R\u{00F8}mer)";
+const REFERENCES_HELP: &str =
+    "References: names like \"triple point of water\", \"body temperature\" or LN2";
 
 fn main() {
-
     let args: Vec<String> = env::args().collect();
     if args.len() == 2 && (args[1] == "-h" || args[1] == "--help") {
         println!("Usage: {} <value> <from_unit> <to_unit>", args[0]);
-        println!("Units: C, F, K");
+        println!("       {} <reference> [<to_unit>]", args[0]);
+        println!("{UNITS_HELP}");
+        println!("{REFERENCES_HELP}");
         return;
     }
 
@@ -79,10 +95,16 @@ fn main() {
         return;
     }
 
-    if args.len() != 3 && args.len() != 4 {
+    // A reference temperature such as "LN2" stands in for the value and
+    // unit, so the to unit comes one argument earlier.
+    let reference = args.get(1).and_then(|name| reference::lookup(name));
+    let to_index = if reference.is_some() { 2 } else { 3 };
+    if args.len() != to_index && args.len() != to_index + 1 {
         eprintln!("Usage: {} <value> <from_unit> <to_unit>", args[0]);
         eprintln!("       {} <value> <from_unit>", args[0]);
-        eprintln!("Units: C, F, K");
+        eprintln!("       {} <reference> [<to_unit>]", args[0]);
+        eprintln!("{UNITS_HELP}");
+        eprintln!("{REFERENCES_HELP}");
         return;
     }
 
@@ -92,33 +114,33 @@ fn main() {
         println!("The current locale is {}", locale);
     }
 
-    let from_value: f64 = match args[1].parse() {
-        Ok(value) => value,
-        Err(_) => {
-            eprintln!("Invalid temperature value: {}", args[1]);
-            return;
-        }
-    };
-
-    let from_unit = match args[2].as_str() {
-        "C" => TemperatureUnit::Celsius,
-        "F" => TemperatureUnit::Fahrenheit,
-        "K" => TemperatureUnit::Kelvin,
-        _ => {
-            eprintln!("Invalid from unit: {}", args[2]);
-            eprintln!("Units: C, F, K");
-            return;
-        }
+    let system_locale = Locale::new(&locale);
+    let from_temp = match reference {
+        Some(reference) => reference.temperature(),
+        None => match parse_input(&args[1], &args[2], &system_locale) {
+            Ok(temp) => temp,
+            Err(TemperatureError::InvalidNumber { .. }) => {
+                eprintln!("Invalid temperature value: {}", args[1]);
+                return;
+            }
+            Err(TemperatureError::InvalidUnit { .. } | TemperatureError::MissingUnit { .. }) => {
+                eprintln!("Invalid from unit: {}", args[2]);
+                eprintln!("{UNITS_HELP}");
+                return;
+            }
+            Err(err) => {
+                eprintln!("Invalid temperature: {err}");
+                return;
+            }
+        },
     };
 
-    let to_unit = if args.len() == 4 {
-        match args[3].as_str() {
-            "C" => TemperatureUnit::Celsius,
-            "F" => TemperatureUnit::Fahrenheit,
-            "K" => TemperatureUnit::Kelvin,
-            _ => {
-                eprintln!("Invalid to unit: {}", args[3]);
-                eprintln!("Units: C, F, K");
+    let to_unit = if let Some(name) = args.get(to_index) {
+        match TemperatureUnit::from_name(name) {
+            Some(unit) => unit,
+            None => {
+                eprintln!("Invalid to unit: {name}");
+                eprintln!("{UNITS_HELP}");
                 return;
             }
         }
@@ -126,16 +148,18 @@ fn main() {
         locale_default_unit(&locale)
     };
 
-    let from_temp = match Temperature::new(from_value, from_unit) {
+    let to_temp = match from_temp.try_to(to_unit) {
         Ok(temp) => temp,
         Err(err) => {
-            eprintln!("Invalid temperature: {err}");
+            eprintln!("Cannot convert temperature: {err}");
             return;
         }
     };
-
-    let to_temp = from_temp.to(to_unit);
-    println!("{from_temp} = {to_temp}");
+    println!(
+        "{} = {}",
+        from_temp.localized(&system_locale),
+        to_temp.localized(&system_locale)
+    );
 }
 
 // Get the default temperature unit for a given locale. For simplicity, we only
@@ -155,9 +179,33 @@ fn locale_default_unit(locale: &str) -> TemperatureUnit {
     }
 }
 
+/// Parses a value and unit from the command line.
+///
+/// Plain input like `37.5 C` reads the same in every locale. Only when that
+/// fails is the locale tried, so the value may use its separators (`37,5`)
+/// and the unit its words (`Grad Celsius`).
+fn parse_input(value: &str, unit: &str, locale: &Locale) -> Result<Temperature, TemperatureError> {
+    let input = format!("{value} {unit}");
+    input
+        .parse()
+        .or_else(|err| Temperature::parse_locale(&input, locale).map_err(|_| err))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{locale_default_unit, TemperatureUnit};
+    use super::{locale_default_unit, parse_input, TemperatureUnit};
+    use convert_temp::locale::Locale;
+
+    #[test]
+    fn input_is_invariant_unless_only_the_locale_reads_it() {
+        let german = Locale::new("de-DE");
+        let parse = |value, unit| parse_input(value, unit, &german).map(|temp| temp.value());
+        assert_eq!(parse("37.5", "C"), Ok(37.5));
+        assert_eq!(parse("1.000", "C"), Ok(1.0));
+        assert_eq!(parse("37,5", "C"), Ok(37.5));
+        assert_eq!(parse("98,6", "Grad Fahrenheit"), Ok(98.6));
+        assert!(parse("abc", "C").is_err());
+    }
 
     #[test]
     fn locale_defaults_to_fahrenheit_for_us_liberia_myanmar() {
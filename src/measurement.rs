@@ -0,0 +1,428 @@
+//! Temperatures with a measurement uncertainty.
+//!
+//! A calibrated reading such as `37.5 ± 0.1 °C` is a `Measurement`: a
+//! `Temperature` with an uncertainty above and below it, in degrees of the
+//! same unit. Converting rescales the uncertainty by the size of a degree
+//! only, ignoring the scales' offsets, so `37.5 ± 0.1 °C` is
+//! `99.5 ± 0.18 °F`. Differences and means combine the uncertainties of
+//! independent readings in quadrature.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::measurement::Measurement;
+//! use convert_temp::TemperatureUnit;
+//!
+//! let reading: Measurement = "37.5 ± 0.1 °C".parse().unwrap();
+//! let fahrenheit = reading.to(TemperatureUnit::Fahrenheit);
+//! assert_eq!(format!("{fahrenheit:.2}"), "99.50 \u{00B1} 0.18 \u{00B0}F");
+//!
+//! let baseline: Measurement = "36.9 ± 0.2 °C".parse().unwrap();
+//! let rise = reading - baseline;
+//! assert_eq!(format!("{rise:.2}"), "0.60 \u{00B1} 0.22 \u{00B0}C");
+//! ```
+
+use core::fmt;
+use core::ops::Sub;
+use core::str::FromStr;
+
+use crate::delta::TemperatureDelta;
+use crate::format;
+use crate::numeric::sealed::Sealed;
+use crate::scale::degree_ratio;
+use crate::temperature::{
+    parse_number, span_of, split_unit, Result, Temperature, TemperatureError, TemperatureUnit,
+};
+
+/// The uncertainty above and below a value, in degrees of its unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Spread {
+    plus: f64,
+    minus: f64,
+}
+
+impl Spread {
+    fn new(plus: f64, minus: f64) -> Result<Spread> {
+        for value in [plus, minus] {
+            if !(value.is_finite() && value >= 0.0) {
+                return Err(TemperatureError::InvalidUncertainty { value });
+            }
+        }
+        Ok(Spread { plus, minus })
+    }
+
+    /// Rescales the spread from `from` degrees to `to` degrees. Scales that
+    /// run backwards, like Delisle, swap the bounds.
+    fn to(self, from: TemperatureUnit, to: TemperatureUnit) -> Spread {
+        let ratio = degree_ratio(from, to).to_f64();
+        if ratio < 0.0 {
+            Spread {
+                plus: self.minus * -ratio,
+                minus: self.plus * -ratio,
+            }
+        } else {
+            Spread {
+                plus: self.plus * ratio,
+                minus: self.minus * ratio,
+            }
+        }
+    }
+
+    /// The spread of `a - b`, for independent `a` and `b` with spreads
+    /// `self` and `other`.
+    fn difference(self, other: Spread) -> Spread {
+        Spread {
+            plus: self.plus.hypot(other.minus),
+            minus: self.minus.hypot(other.plus),
+        }
+    }
+
+    /// Writes ` ± 0.1` or ` +0.2/-0.1`.
+    fn write(self, w: &mut dyn fmt::Write, precision: Option<usize>) -> fmt::Result {
+        if self.plus == self.minus {
+            w.write_str(" \u{00B1} ")?;
+            self.plus.fmt_value(w, precision)
+        } else {
+            w.write_str(" +")?;
+            self.plus.fmt_value(w, precision)?;
+            w.write_str("/-")?;
+            self.minus.fmt_value(w, precision)
+        }
+    }
+}
+
+/// Writes `37.5 ± 0.1 °C`, honouring the formatter's flags like
+/// `Temperature` does.
+fn fmt_measured(
+    f: &mut fmt::Formatter<'_>,
+    value: f64,
+    unit: TemperatureUnit,
+    spread: Spread,
+) -> fmt::Result {
+    let precision = f.precision();
+    let sign_plus = f.sign_plus();
+    let long = f.alternate();
+    format::pad(f, |w| {
+        format::write_plus(w, sign_plus, value)?;
+        value.fmt_value(w, precision)?;
+        spread.write(w, precision)?;
+        if !long {
+            w.write_char(' ')?;
+        }
+        format::write_unit(w, unit, long, format::is_singular(value, precision))
+    })
+}
+
+/// A temperature with its uncertainty.
+///
+/// The uncertainty may differ above and below the value. Both bounds are
+/// finite and not negative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    temp: Temperature,
+    spread: Spread,
+}
+
+impl Measurement {
+    /// Creates a measurement with the same uncertainty either side, like
+    /// `37.5 ± 0.1 °C`.
+    ///
+    /// Fails with `InvalidUncertainty` if `uncertainty` is negative or not
+    /// finite.
+    pub fn new(temp: Temperature, uncertainty: f64) -> Result<Measurement> {
+        Measurement::asymmetric(temp, uncertainty, uncertainty)
+    }
+
+    /// Creates a measurement that may be `plus` degrees higher or `minus`
+    /// degrees lower, like `37.5 +0.2/-0.1 °C`.
+    pub fn asymmetric(temp: Temperature, plus: f64, minus: f64) -> Result<Measurement> {
+        Ok(Measurement {
+            temp,
+            spread: Spread::new(plus, minus)?,
+        })
+    }
+
+    /// Returns the measured temperature.
+    pub fn temperature(&self) -> Temperature {
+        self.temp
+    }
+
+    /// Returns how much higher the temperature may be, in degrees of its
+    /// unit.
+    pub fn plus(&self) -> f64 {
+        self.spread.plus
+    }
+
+    /// Returns how much lower the temperature may be, in degrees of its
+    /// unit.
+    pub fn minus(&self) -> f64 {
+        self.spread.minus
+    }
+
+    /// Returns true if the uncertainty is the same either side.
+    pub fn is_symmetric(&self) -> bool {
+        self.spread.plus == self.spread.minus
+    }
+
+    /// Converts this measurement to the requested unit.
+    ///
+    /// The uncertainty is rescaled by the size of a degree; on the Delisle
+    /// scale, which runs backwards, the upper and lower bounds swap.
+    ///
+    /// # Panics
+    /// Panics if the converted temperature is not finite, like
+    /// [`Temperature::to`].
+    pub fn to(&self, unit: TemperatureUnit) -> Measurement {
+        Measurement {
+            temp: self.temp.to(unit),
+            spread: self.spread.to(self.temp.unit(), unit),
+        }
+    }
+
+    /// Returns the mean of independent readings, in the first reading's
+    /// unit, or `None` if there are none.
+    ///
+    /// The uncertainty of the mean of `n` readings is the quadrature sum of
+    /// theirs divided by `n`.
+    pub fn mean(readings: &[Measurement]) -> Option<Measurement> {
+        let unit = readings.first()?.temp.unit();
+        let (mut sum, mut plus, mut minus) = (0.0, 0.0, 0.0);
+        for reading in readings {
+            let reading = reading.to(unit);
+            sum += reading.temp.value();
+            plus += reading.spread.plus * reading.spread.plus;
+            minus += reading.spread.minus * reading.spread.minus;
+        }
+
+        let count = readings.len() as f64;
+        Some(Measurement {
+            temp: Temperature::new(sum / count, unit).ok()?,
+            spread: Spread {
+                plus: plus.sqrt() / count,
+                minus: minus.sqrt() / count,
+            },
+        })
+    }
+}
+
+/// A reading with no uncertainty.
+impl From<Temperature> for Measurement {
+    fn from(temp: Temperature) -> Measurement {
+        Measurement {
+            temp,
+            spread: Spread {
+                plus: 0.0,
+                minus: 0.0,
+            },
+        }
+    }
+}
+
+/// Writes `37.5 ± 0.1 °C`, or `37.5 +0.2/-0.1 °C` if the uncertainty is
+/// asymmetric. Honours the same flags as `Temperature`; precision applies to
+/// the uncertainty too.
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_measured(f, self.temp.value(), self.temp.unit(), self.spread)
+    }
+}
+
+/// Parses a measurement like `"37.5 ± 0.1 °C"`, `"37.5+/-0.1C"`,
+/// `"(37.5 ± 0.1) °C"` or `"37.5 +0.2/-0.1 °C"`.
+///
+/// The unit is anything `Temperature` accepts. A plain temperature like
+/// `"37.5 °C"` parses with no uncertainty.
+impl FromStr for Measurement {
+    type Err = TemperatureError;
+
+    fn from_str(input: &str) -> Result<Measurement> {
+        let (span, unit) = split_unit(input)?;
+        let body = &input[span];
+        let body = body
+            .strip_prefix('(')
+            .and_then(|body| body.strip_suffix(')'))
+            .unwrap_or(body);
+
+        let separator = ["\u{00B1}", "+/-", "+-"]
+            .into_iter()
+            .find_map(|separator| body.split_once(separator));
+        let (value, plus, minus) = match separator {
+            Some((value, uncertainty)) => {
+                let uncertainty = parse_number(input, uncertainty)?;
+                (value, uncertainty, uncertainty)
+            }
+            None => match body.rsplit_once('/') {
+                Some((rest, minus)) => {
+                    let minus = minus.trim_start();
+                    let minus = minus.strip_prefix(['-', '\u{2212}']).ok_or_else(|| {
+                        TemperatureError::InvalidNumber {
+                            span: span_of(input, minus),
+                            source: None,
+                        }
+                    })?;
+                    let (value, plus) =
+                        rest.rsplit_once('+')
+                            .ok_or_else(|| TemperatureError::InvalidNumber {
+                                span: span_of(input, body),
+                                source: None,
+                            })?;
+                    (
+                        value,
+                        parse_number(input, plus)?,
+                        parse_number(input, minus)?,
+                    )
+                }
+                None => (body, 0.0, 0.0),
+            },
+        };
+
+        let temp = Temperature::new(parse_number(input, value)?, unit)?;
+        Measurement::asymmetric(temp, plus, minus)
+    }
+}
+
+/// A difference between two measurements, with its uncertainty.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasuredDelta {
+    delta: TemperatureDelta,
+    spread: Spread,
+}
+
+impl MeasuredDelta {
+    /// Returns the difference.
+    pub fn delta(&self) -> TemperatureDelta {
+        self.delta
+    }
+
+    /// Returns how much larger the difference may be, in degrees of its
+    /// unit.
+    pub fn plus(&self) -> f64 {
+        self.spread.plus
+    }
+
+    /// Returns how much smaller the difference may be, in degrees of its
+    /// unit.
+    pub fn minus(&self) -> f64 {
+        self.spread.minus
+    }
+
+    /// Converts this difference to the requested unit, rescaling the
+    /// uncertainty like [`Measurement::to`].
+    pub fn to(&self, unit: TemperatureUnit) -> MeasuredDelta {
+        MeasuredDelta {
+            delta: self.delta.to(unit),
+            spread: self.spread.to(self.delta.unit(), unit),
+        }
+    }
+}
+
+/// Writes `0.6 ± 0.22 °C`, like `Measurement`.
+impl fmt::Display for MeasuredDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_measured(f, self.delta.value(), self.delta.unit(), self.spread)
+    }
+}
+
+/// The difference between two independent measurements, in the left-hand
+/// unit. The uncertainties combine in quadrature.
+impl Sub for Measurement {
+    type Output = MeasuredDelta;
+
+    fn sub(self, other: Measurement) -> MeasuredDelta {
+        let other = other.to(self.temp.unit());
+        MeasuredDelta {
+            delta: self.temp - other.temp,
+            spread: self.spread.difference(other.spread),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Measurement;
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+
+    fn celsius(value: f64, uncertainty: f64) -> Measurement {
+        let temp = Temperature::new(value, TemperatureUnit::Celsius).expect("valid");
+        Measurement::new(temp, uncertainty).expect("valid")
+    }
+
+    #[test]
+    fn conversion_scales_uncertainty_only() {
+        let reading = celsius(37.5, 0.1);
+        let kelvin = reading.to(TemperatureUnit::Kelvin);
+        assert_eq!(kelvin.temperature().value(), 310.65);
+        assert_eq!(kelvin.plus(), 0.1);
+
+        let fahrenheit = reading.to(TemperatureUnit::Fahrenheit);
+        assert!((fahrenheit.plus() - 0.18).abs() < 1e-12);
+        assert!(fahrenheit.is_symmetric());
+    }
+
+    #[test]
+    fn delisle_swaps_asymmetric_bounds() {
+        let temp = Temperature::new(20.0, TemperatureUnit::Celsius).expect("valid");
+        let reading = Measurement::asymmetric(temp, 0.2, 0.1).expect("valid");
+        let delisle = reading.to(TemperatureUnit::Delisle);
+        assert_eq!(delisle.temperature().value(), 120.0);
+        assert!((delisle.plus() - 0.15).abs() < 1e-12);
+        assert!((delisle.minus() - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn differences_and_means_add_in_quadrature() {
+        let rise = celsius(40.0, 0.3) - celsius(30.0, 0.4);
+        assert_eq!(rise.delta().value(), 10.0);
+        assert!((rise.plus() - 0.5).abs() < 1e-12);
+        assert!((rise.to(TemperatureUnit::Fahrenheit).plus() - 0.9).abs() < 1e-12);
+
+        let readings = [celsius(20.0, 0.3), celsius(22.0, 0.4)];
+        let mean = Measurement::mean(&readings).expect("not empty");
+        assert_eq!(mean.temperature().value(), 21.0);
+        assert!((mean.plus() - 0.25).abs() < 1e-12);
+        assert_eq!(Measurement::mean(&[]), None);
+    }
+
+    #[test]
+    fn display_and_parse() {
+        assert_eq!(
+            format!("{}", celsius(37.5, 0.1)),
+            "37.5 \u{00B1} 0.1 \u{00B0}C"
+        );
+        assert_eq!(
+            format!("{:#}", celsius(37.5, 0.1)),
+            "37.5 \u{00B1} 0.1 degrees Celsius"
+        );
+        let temp = Temperature::new(300.0, TemperatureUnit::Kelvin).expect("valid");
+        let asymmetric = Measurement::asymmetric(temp, 0.2, 0.1).expect("valid");
+        assert_eq!(format!("{asymmetric}"), "300 +0.2/-0.1 K");
+
+        for text in [
+            "37.5 \u{00B1} 0.1 \u{00B0}C",
+            "37.5+/-0.1C",
+            "(37.5 \u{00B1} 0.1) \u{00B0}C",
+        ] {
+            assert_eq!(
+                text.parse::<Measurement>(),
+                Ok(celsius(37.5, 0.1)),
+                "{text}"
+            );
+        }
+        assert_eq!(
+            format!("{asymmetric}").parse::<Measurement>(),
+            Ok(asymmetric)
+        );
+        assert_eq!(
+            "21 \u{00B0}C".parse::<Measurement>(),
+            Ok(celsius(21.0, 0.0))
+        );
+
+        let err = "37.5 \u{00B1} x \u{00B0}C"
+            .parse::<Measurement>()
+            .unwrap_err();
+        assert_eq!(err.span(), Some(8..9));
+        let err = "37.5 \u{00B1} -0.1 \u{00B0}C"
+            .parse::<Measurement>()
+            .unwrap_err();
+        assert_eq!(err, TemperatureError::InvalidUncertainty { value: -0.1 });
+    }
+}
@@ -0,0 +1,528 @@
+//! Numeric storage for temperature values.
+//!
+//! `Temperature<T>` stores its value as any `Scalar`: `f64` (the default),
+//! `f32`, or a decimal fixed-point integer like `Fixed<i32, 3>`, which holds
+//! thousandths of a degree. Conversions between scales use the exact scale
+//! definitions for every representation; fixed-point results are rounded
+//! half away from zero to the nearest step.
+//!
+//! Widening to a representation that holds every value of the narrower one
+//! (`f32` to `f64`, `Fixed<i16, D>` to `Fixed<i32, D>`) is lossless and uses
+//! `From`; the one exception is an `f32` at absolute zero, which becomes the
+//! `f64` absolute zero. Narrowing uses `TryFrom`, and `Temperature::try_cast`
+//! converts between any two representations, failing if the value doesn't
+//! fit.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::numeric::Milli;
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! // 37.5 °C stored as 37500 millidegrees.
+//! let reading = Temperature::new(Milli::from_raw(37_500), TemperatureUnit::Celsius).unwrap();
+//! let fahrenheit = reading.to(TemperatureUnit::Fahrenheit);
+//! assert_eq!(fahrenheit.value().raw(), 99_500);
+//! assert_eq!(format!("{fahrenheit}"), "99.5\u{00B0}F");
+//!
+//! let wide: Temperature<f64> = reading.try_cast().unwrap();
+//! assert_eq!(wide.value(), 37.5);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::num::ParseFloatError;
+
+use crate::scale::{exact_affine, Affine};
+use crate::temperature::{Result, Temperature, TemperatureError, TemperatureUnit};
+
+/// A numeric type that can store a temperature value.
+///
+/// This trait is sealed: it is implemented for `f32`, `f64` and `Fixed`.
+pub trait Scalar: Copy + PartialEq + fmt::Debug + sealed::Sealed {
+    /// Returns the nearest `f64` to this value.
+    fn to_f64(self) -> f64;
+}
+
+pub(crate) mod sealed {
+    use core::cmp::Ordering;
+    use core::fmt;
+    use core::num::ParseFloatError;
+
+    use crate::temperature::TemperatureUnit;
+
+    /// The operations `Temperature<T>` needs from its value type.
+    pub trait Sealed: Sized {
+        /// Converts a reading between units, or returns `None` if the result
+        /// doesn't fit.
+        fn convert(self, from: TemperatureUnit, to: TemperatureUnit) -> Option<Self>;
+
+        /// Compares this reading with absolute zero on the given scale.
+        fn compare_absolute_zero(self, unit: TemperatureUnit) -> Option<Ordering>;
+
+        /// Writes the value without a unit, rounded to `precision` decimal
+        /// places if given.
+        fn fmt_value(&self, w: &mut dyn fmt::Write, precision: Option<usize>) -> fmt::Result;
+
+        /// Parses a decimal number. Float types report why parsing failed.
+        fn parse_value(input: &str) -> Result<Self, Option<ParseFloatError>>;
+
+        /// Returns the value as `raw / 10^decimals`, if it is a decimal.
+        fn to_decimal(self) -> Option<(i128, u32)>;
+
+        /// Creates a value from `raw / 10^decimals`, rounding if needed.
+        fn from_decimal(raw: i128, decimals: u32) -> Option<Self>;
+
+        /// Creates a value from an `f64`, rounding if needed.
+        fn from_f64(value: f64) -> Option<Self>;
+    }
+}
+
+macro_rules! impl_float_scalar {
+    ($float:ty) => {
+        impl Scalar for $float {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+
+        impl sealed::Sealed for $float {
+            fn convert(self, from: TemperatureUnit, to: TemperatureUnit) -> Option<Self> {
+                // Extreme magnitudes can overflow to infinity.
+                let value = Affine::between(from, to).apply(self as f64) as $float;
+                value.is_finite().then_some(value)
+            }
+
+            fn compare_absolute_zero(self, unit: TemperatureUnit) -> Option<Ordering> {
+                // Compare with absolute zero as rounded to this type, so the
+                // nearest representable reading is accepted.
+                let zero = unit.scale().absolute_zero.to_f64() as $float;
+                self.partial_cmp(&zero)
+            }
+
+            fn fmt_value(&self, w: &mut dyn fmt::Write, precision: Option<usize>) -> fmt::Result {
+                match precision {
+                    Some(precision) => write!(w, "{:.*}", precision, self),
+                    None => write!(w, "{}", self),
+                }
+            }
+
+            fn parse_value(input: &str) -> core::result::Result<Self, Option<ParseFloatError>> {
+                input.parse().map_err(Some)
+            }
+
+            fn to_decimal(self) -> Option<(i128, u32)> {
+                None
+            }
+
+            fn from_decimal(raw: i128, decimals: u32) -> Option<Self> {
+                let value = raw as f64 / pow10(decimals);
+                Self::from_f64(value)
+            }
+
+            fn from_f64(value: f64) -> Option<Self> {
+                let narrowed = value as $float;
+                (narrowed.is_finite() || !value.is_finite()).then_some(narrowed)
+            }
+        }
+    };
+}
+
+impl_float_scalar!(f32);
+impl_float_scalar!(f64);
+
+/// A decimal fixed-point number: `raw / 10^DECIMALS`, stored in the integer
+/// type `I`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<I, const DECIMALS: u32>(I);
+
+/// Thousandths of a degree, e.g. millikelvin.
+pub type Milli = Fixed<i32, 3>;
+
+/// Hundredths of a degree, e.g. centidegrees Celsius.
+pub type Centi = Fixed<i32, 2>;
+
+impl<I: Copy, const DECIMALS: u32> Fixed<I, DECIMALS> {
+    /// Creates a value from its raw integer, in steps of `10^-DECIMALS`.
+    pub const fn from_raw(raw: I) -> Fixed<I, DECIMALS> {
+        Fixed(raw)
+    }
+
+    /// Returns the raw integer, in steps of `10^-DECIMALS`.
+    pub const fn raw(self) -> I {
+        self.0
+    }
+}
+
+/// Returns `10^exponent`, exact up to `10^22`.
+fn pow10(exponent: u32) -> f64 {
+    (0..exponent).fold(1.0, |power, _| power * 10.0)
+}
+
+/// Divides `numerator` by `denominator`, rounding half away from zero.
+fn div_round(numerator: i128, denominator: i128) -> Option<i128> {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        let away = if (numerator < 0) == (denominator < 0) {
+            1
+        } else {
+            -1
+        };
+        quotient.checked_add(away)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// Parses a plain decimal like `-40.125` as `raw / 10^decimals`.
+fn parse_decimal(input: &str) -> Option<(i128, u32)> {
+    let (negative, digits) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    let mut raw: i128 = 0;
+    for c in whole.chars().chain(fraction.chars()) {
+        raw = raw.checked_mul(10)?.checked_add(c.to_digit(10)? as i128)?;
+    }
+    if negative {
+        raw = -raw;
+    }
+    Some((raw, fraction.len() as u32))
+}
+
+macro_rules! impl_fixed_scalar {
+    ($int:ty) => {
+        impl<const DECIMALS: u32> Scalar for Fixed<$int, DECIMALS> {
+            fn to_f64(self) -> f64 {
+                self.0 as f64 / pow10(DECIMALS)
+            }
+        }
+
+        impl<const DECIMALS: u32> sealed::Sealed for Fixed<$int, DECIMALS> {
+            fn convert(self, from: TemperatureUnit, to: TemperatureUnit) -> Option<Self> {
+                // raw_to = (raw_from * scale + offset * 10^DECIMALS) / divisor
+                let (scale, offset, divisor) = exact_affine(from, to);
+                let step = 10i128.checked_pow(DECIMALS)?;
+                let numerator = (self.0 as i128)
+                    .checked_mul(scale as i128)?
+                    .checked_add((offset as i128).checked_mul(step)?)?;
+                let raw = div_round(numerator, divisor as i128)?;
+                <$int>::try_from(raw).ok().map(Fixed)
+            }
+
+            fn compare_absolute_zero(self, unit: TemperatureUnit) -> Option<Ordering> {
+                // raw / 10^DECIMALS <=> numerator / denominator
+                let zero = unit.scale().absolute_zero;
+                let step = 10i128.checked_pow(DECIMALS)?;
+                let lhs = (self.0 as i128).checked_mul(zero.denominator as i128)?;
+                let rhs = (zero.numerator as i128).checked_mul(step)?;
+                Some(lhs.cmp(&rhs))
+            }
+
+            fn fmt_value(&self, w: &mut dyn fmt::Write, precision: Option<usize>) -> fmt::Result {
+                let mut raw = self.0 as i128;
+                let mut decimals = DECIMALS as usize;
+                if let Some(precision) = precision.filter(|&precision| precision < decimals) {
+                    // A step too large for an i128 rounds every raw value to zero.
+                    raw = match 10i128.checked_pow((decimals - precision) as u32) {
+                        Some(dropped) => div_round(raw, dropped).ok_or(fmt::Error)?,
+                        None => 0,
+                    };
+                    decimals = precision;
+                }
+
+                let magnitude = raw.unsigned_abs();
+                let sign = if raw < 0 { "-" } else { "" };
+                // Likewise, with a step too large for a u128 everything is fraction.
+                let (whole, mut fraction) = match 10u128.checked_pow(decimals as u32) {
+                    Some(step) => (magnitude / step, magnitude % step),
+                    None => (0, magnitude),
+                };
+                if precision.is_none() {
+                    // Match f64's Display: no trailing zeros.
+                    while decimals > 0 && fraction % 10 == 0 {
+                        fraction /= 10;
+                        decimals -= 1;
+                    }
+                }
+                write!(w, "{sign}{whole}")?;
+                if decimals > 0 || precision.is_some_and(|precision| precision > 0) {
+                    w.write_char('.')?;
+                }
+                if decimals > 0 {
+                    write!(w, "{fraction:0decimals$}")?;
+                }
+                // Precision beyond DECIMALS pads with zeros.
+                for _ in decimals..precision.unwrap_or(0) {
+                    w.write_char('0')?;
+                }
+                Ok(())
+            }
+
+            fn parse_value(input: &str) -> core::result::Result<Self, Option<ParseFloatError>> {
+                parse_decimal(input)
+                    .and_then(|(raw, decimals)| Self::from_decimal(raw, decimals))
+                    .ok_or(None)
+            }
+
+            fn to_decimal(self) -> Option<(i128, u32)> {
+                Some((self.0 as i128, DECIMALS))
+            }
+
+            fn from_decimal(raw: i128, decimals: u32) -> Option<Self> {
+                let raw = if decimals <= DECIMALS {
+                    raw.checked_mul(10i128.checked_pow(DECIMALS - decimals)?)?
+                } else {
+                    div_round(raw, 10i128.checked_pow(decimals - DECIMALS)?)?
+                };
+                <$int>::try_from(raw).ok().map(Fixed)
+            }
+
+            fn from_f64(value: f64) -> Option<Self> {
+                let scaled = value * pow10(DECIMALS);
+                // The range check also rejects NaN.
+                if !(scaled > i128::MIN as f64 && scaled < i128::MAX as f64) {
+                    return None;
+                }
+                let truncated = scaled as i128;
+                let raw = if (scaled - truncated as f64).abs() >= 0.5 {
+                    truncated + scaled.signum() as i128
+                } else {
+                    truncated
+                };
+                <$int>::try_from(raw).ok().map(Fixed)
+            }
+        }
+    };
+}
+
+impl_fixed_scalar!(i16);
+impl_fixed_scalar!(i32);
+impl_fixed_scalar!(i64);
+
+impl<T: Scalar> Temperature<T> {
+    /// Converts this temperature to another numeric representation, keeping
+    /// its unit.
+    ///
+    /// Fails with `OutOfRange` if the value doesn't fit, or with
+    /// `BelowAbsoluteZero` if rounding it would take it below absolute zero.
+    pub fn try_cast<U: Scalar>(self) -> Result<Temperature<U>> {
+        let value = match self.value().to_decimal() {
+            Some((raw, decimals)) => U::from_decimal(raw, decimals),
+            None => U::from_f64(self.value().to_f64()),
+        };
+        let value = value.ok_or(TemperatureError::OutOfRange {
+            value: self.value().to_f64(),
+            unit: self.unit(),
+        })?;
+        Temperature::new(value, self.unit())
+    }
+}
+
+/// Widens an `f32` reading exactly, except at absolute zero.
+impl From<Temperature<f32>> for Temperature<f64> {
+    fn from(temp: Temperature<f32>) -> Temperature<f64> {
+        // The f32 check accepts absolute zero as rounded to f32, like
+        // -459.67 °F, which widens to a hair below the f64 absolute zero.
+        // No other f32 reading is that close, so it means absolute zero.
+        let unit = temp.unit();
+        Temperature::new(temp.value().into(), unit).unwrap_or_else(|_| {
+            Temperature::new_unchecked(unit.scale().absolute_zero.to_f64(), unit)
+        })
+    }
+}
+
+/// Lossless widening between fixed-point representations.
+macro_rules! impl_widen {
+    (fixed $from:ty => $to:ty) => {
+        impl<const DECIMALS: u32> From<Temperature<Fixed<$from, DECIMALS>>>
+            for Temperature<Fixed<$to, DECIMALS>>
+        {
+            fn from(
+                temp: Temperature<Fixed<$from, DECIMALS>>,
+            ) -> Temperature<Fixed<$to, DECIMALS>> {
+                Temperature::new_unchecked(Fixed(temp.value().0.into()), temp.unit())
+            }
+        }
+    };
+}
+
+impl_widen!(fixed i16 => i32);
+impl_widen!(fixed i16 => i64);
+impl_widen!(fixed i32 => i64);
+
+/// Checked narrowing between representations.
+macro_rules! impl_narrow {
+    ($from:ty => $to:ty) => {
+        impl TryFrom<Temperature<$from>> for Temperature<$to> {
+            type Error = TemperatureError;
+
+            fn try_from(temp: Temperature<$from>) -> Result<Temperature<$to>> {
+                temp.try_cast()
+            }
+        }
+    };
+    (fixed $from:ty => $to:ty) => {
+        impl<const DECIMALS: u32> TryFrom<Temperature<Fixed<$from, DECIMALS>>>
+            for Temperature<Fixed<$to, DECIMALS>>
+        {
+            type Error = TemperatureError;
+
+            fn try_from(
+                temp: Temperature<Fixed<$from, DECIMALS>>,
+            ) -> Result<Temperature<Fixed<$to, DECIMALS>>> {
+                temp.try_cast()
+            }
+        }
+    };
+}
+
+impl_narrow!(f64 => f32);
+impl_narrow!(fixed i64 => i32);
+impl_narrow!(fixed i64 => i16);
+impl_narrow!(fixed i32 => i16);
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Centi, Fixed, Milli};
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+
+    #[test]
+    fn f32_converts_and_validates() {
+        let temp = Temperature::new(100.0f32, TemperatureUnit::Celsius).expect("valid");
+        let fahrenheit = temp.to(TemperatureUnit::Fahrenheit);
+        assert_eq!(fahrenheit.value(), 212.0f32);
+        assert!(Temperature::new(-459.67f32, TemperatureUnit::Fahrenheit).is_ok());
+        assert!(Temperature::new(-460.0f32, TemperatureUnit::Fahrenheit).is_err());
+    }
+
+    #[test]
+    fn fixed_point_converts_with_rounding() {
+        let temp =
+            Temperature::new(Centi::from_raw(3_750), TemperatureUnit::Celsius).expect("valid");
+        assert_eq!(temp.to(TemperatureUnit::Kelvin).value().raw(), 31_065);
+        // 100 °F = 37.777... °C, rounded to the nearest hundredth.
+        let temp =
+            Temperature::new(Centi::from_raw(10_000), TemperatureUnit::Fahrenheit).expect("valid");
+        assert_eq!(temp.to(TemperatureUnit::Celsius).value().raw(), 3_778);
+    }
+
+    #[test]
+    fn fixed_point_absolute_zero_is_exact() {
+        let zero =
+            Temperature::new(Centi::from_raw(-27_315), TemperatureUnit::Celsius).expect("valid");
+        assert_eq!(zero.to(TemperatureUnit::Kelvin).value().raw(), 0);
+        assert!(Temperature::new(Centi::from_raw(-27_316), TemperatureUnit::Celsius).is_err());
+        assert!(Temperature::new(Milli::from_raw(559_725), TemperatureUnit::Delisle).is_ok());
+        assert!(Temperature::new(Milli::from_raw(559_726), TemperatureUnit::Delisle).is_err());
+    }
+
+    #[test]
+    fn fixed_point_formats_with_any_decimals() {
+        let whole = Temperature::new(Fixed::<i64, 0>::from_raw(37), TemperatureUnit::Celsius)
+            .expect("valid");
+        assert_eq!(format!("{whole}"), "37°C");
+        assert_eq!(format!("{whole:.2}"), "37.00°C");
+        assert_eq!(format!("{whole:.0}"), "37°C");
+
+        let tiny = Temperature::new(Fixed::<i64, 50>::from_raw(-15), TemperatureUnit::Celsius)
+            .expect("valid");
+        assert_eq!(format!("{tiny:.2}"), "0.00°C");
+        assert_eq!(format!("{tiny}"), format!("-0.{}15°C", "0".repeat(48)));
+    }
+
+    #[test]
+    fn fixed_point_overflow_is_reported() {
+        let hot = Temperature::new(Fixed::<i16, 2>::from_raw(30_000), TemperatureUnit::Celsius)
+            .expect("valid");
+        assert!(hot.checked_to(TemperatureUnit::Fahrenheit).is_none());
+        assert!(hot.checked_to(TemperatureUnit::Reaumur).is_some());
+    }
+
+    #[test]
+    fn fixed_point_display_and_parse() {
+        let temp = Temperature::<Milli>::from_str("-40.125C").expect("valid");
+        assert_eq!(temp.value().raw(), -40_125);
+        assert_eq!(format!("{temp}"), "-40.125\u{00B0}C");
+        let parsed = Temperature::<Milli>::from_str(&format!("{temp}")).expect("round-trips");
+        assert_eq!(parsed.value(), temp.value());
+
+        let temp = Temperature::<Centi>::from_str("300K").expect("valid");
+        assert_eq!(temp.value().raw(), 30_000);
+        assert_eq!(format!("{temp}"), "300K");
+
+        let temp = Temperature::<Centi>::from_str("0.005C").expect("valid");
+        assert_eq!(temp.value().raw(), 1);
+        assert!(Temperature::<Centi>::from_str("1e3C").is_err());
+    }
+
+    #[test]
+    fn widening_is_lossless() {
+        let narrow = Temperature::new(37.1f32, TemperatureUnit::Celsius).expect("valid");
+        let wide: Temperature<f64> = narrow.into();
+        assert_eq!(wide.value(), 37.1f32 as f64);
+
+        for unit in TemperatureUnit::ALL {
+            let zero = unit.scale().absolute_zero.to_f64();
+            let narrow = Temperature::new(zero as f32, unit).expect("valid");
+            let wide: Temperature<f64> = narrow.into();
+            // Either exact, or clamped up from just below absolute zero.
+            assert!(Temperature::new(wide.value(), unit).is_ok(), "{unit:?}");
+            assert!(wide.value() == zero || wide.value() == f64::from(narrow.value()));
+        }
+        let narrow = Temperature::new(-459.67f32, TemperatureUnit::Fahrenheit).expect("valid");
+        let wide: Temperature<f64> = narrow.into();
+        assert_eq!(wide.value(), -459.67);
+        let narrow = Temperature::new(0.0f32, TemperatureUnit::Kelvin).expect("valid");
+        let wide: Temperature<f64> = narrow.into();
+        assert_eq!(wide.value(), 0.0);
+
+        let narrow = Temperature::new(Fixed::<i16, 2>::from_raw(-27_315), TemperatureUnit::Celsius)
+            .expect("valid");
+        let wide: Temperature<Centi> = narrow.into();
+        assert_eq!(wide.value().raw(), -27_315);
+    }
+
+    #[test]
+    fn narrowing_is_checked() {
+        let wide = Temperature::new(1e300, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(
+            Temperature::<f32>::try_from(wide).unwrap_err(),
+            TemperatureError::OutOfRange {
+                value: 1e300,
+                unit: TemperatureUnit::Kelvin
+            }
+        );
+
+        let wide =
+            Temperature::new(Milli::from_raw(30_000), TemperatureUnit::Kelvin).expect("valid");
+        let narrow: Temperature<Fixed<i16, 3>> = wide.try_into().expect("fits");
+        assert_eq!(narrow.value().raw(), 30_000);
+
+        let wide =
+            Temperature::new(Milli::from_raw(40_000), TemperatureUnit::Kelvin).expect("valid");
+        assert!(Temperature::<Fixed<i16, 3>>::try_from(wide).is_err());
+    }
+
+    #[test]
+    fn try_cast_between_representations() {
+        let temp = Temperature::new(37.777_f64, TemperatureUnit::Celsius).expect("valid");
+        let centi: Temperature<Centi> = temp.try_cast().expect("fits");
+        assert_eq!(centi.value().raw(), 3_778);
+
+        let milli: Temperature<Milli> = centi.try_cast().expect("fits");
+        assert_eq!(milli.value().raw(), 37_780);
+
+        // Rounding -273.15 °C to tenths would drop below absolute zero.
+        let zero =
+            Temperature::new(Centi::from_raw(-27_315), TemperatureUnit::Celsius).expect("valid");
+        assert!(zero.try_cast::<Fixed<i32, 1>>().is_err());
+    }
+}
@@ -0,0 +1,400 @@
+//! Ranges of temperatures.
+//!
+//! Specifications give ranges like "operating range -20..60 °C" or "store
+//! at 2–8 °C". A `TemperatureRange` holds the colder and hotter ends of such
+//! a range, either of which may be excluded, and answers whether a
+//! temperature or another range falls within it.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::range::TemperatureRange;
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! let operating: TemperatureRange = "-20..60C".parse().unwrap();
+//! let storage: TemperatureRange = "2\u{2013}8 \u{00B0}C".parse().unwrap();
+//! assert!(operating.contains(Temperature::new(98.6, TemperatureUnit::Fahrenheit).unwrap()));
+//! assert_eq!(operating.intersection(&storage), Some(storage));
+//!
+//! let fahrenheit = operating.to(TemperatureUnit::Fahrenheit);
+//! assert_eq!(format!("{fahrenheit}"), "-4\u{2013}140\u{00B0}F");
+//! assert_eq!(format!("{}", operating.width()), "80\u{00B0}C");
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::delta::TemperatureDelta;
+use crate::format;
+use crate::numeric::sealed::Sealed;
+use crate::temperature::{
+    parse_number, split_unit, Result, Temperature, TemperatureError, TemperatureUnit,
+};
+
+/// Which ends of a range are included, in interval notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bounds {
+    /// `[low, high]`: both ends included.
+    Closed,
+    /// `(low, high)`: both ends excluded.
+    Open,
+    /// `[low, high)`: the colder end included, like Rust's `low..high`.
+    ClosedOpen,
+    /// `(low, high]`: the hotter end included.
+    OpenClosed,
+}
+
+impl Bounds {
+    fn from_ends(low_inclusive: bool, high_inclusive: bool) -> Bounds {
+        match (low_inclusive, high_inclusive) {
+            (true, true) => Bounds::Closed,
+            (false, false) => Bounds::Open,
+            (true, false) => Bounds::ClosedOpen,
+            (false, true) => Bounds::OpenClosed,
+        }
+    }
+
+    /// Returns true if the colder end is included.
+    pub fn low_inclusive(self) -> bool {
+        matches!(self, Bounds::Closed | Bounds::ClosedOpen)
+    }
+
+    /// Returns true if the hotter end is included.
+    pub fn high_inclusive(self) -> bool {
+        matches!(self, Bounds::Closed | Bounds::OpenClosed)
+    }
+}
+
+/// A range of temperatures between a colder and a hotter end.
+///
+/// Both ends are kept in the same unit. Ends are ordered by physical
+/// temperature, so on the Delisle scale, which runs backwards, the colder
+/// end has the larger reading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemperatureRange {
+    low: Temperature,
+    high: Temperature,
+    bounds: Bounds,
+}
+
+impl TemperatureRange {
+    /// Creates a range from `low` to `high`, in `low`'s unit.
+    ///
+    /// Fails with `InvertedRange` if `low` is hotter than `high`. Equal ends
+    /// with an excluded bound make an empty range.
+    pub fn new(low: Temperature, high: Temperature, bounds: Bounds) -> Result<TemperatureRange> {
+        let high = high.to(low.unit());
+        if low > high {
+            return Err(TemperatureError::InvertedRange { low, high });
+        }
+        Ok(TemperatureRange { low, high, bounds })
+    }
+
+    /// Creates a range including both ends, like `2–8 °C`.
+    pub fn inclusive(low: Temperature, high: Temperature) -> Result<TemperatureRange> {
+        TemperatureRange::new(low, high, Bounds::Closed)
+    }
+
+    /// Returns the colder end.
+    pub fn low(&self) -> Temperature {
+        self.low
+    }
+
+    /// Returns the hotter end.
+    pub fn high(&self) -> Temperature {
+        self.high
+    }
+
+    /// Returns which ends are included.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// Returns the unit of both ends.
+    pub fn unit(&self) -> TemperatureUnit {
+        self.low.unit()
+    }
+
+    /// Returns true if the range contains no temperatures.
+    pub fn is_empty(&self) -> bool {
+        self.low == self.high && self.bounds != Bounds::Closed
+    }
+
+    /// Returns true if `temp` is within the range. `temp` may be in any unit.
+    pub fn contains(&self, temp: Temperature) -> bool {
+        let above_low = if self.bounds.low_inclusive() {
+            temp >= self.low
+        } else {
+            temp > self.low
+        };
+        let below_high = if self.bounds.high_inclusive() {
+            temp <= self.high
+        } else {
+            temp < self.high
+        };
+        above_low && below_high
+    }
+
+    /// Returns true if some temperature is in both ranges.
+    pub fn overlaps(&self, other: &TemperatureRange) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the temperatures in both ranges, in this range's unit, or
+    /// `None` if there are none.
+    pub fn intersection(&self, other: &TemperatureRange) -> Option<TemperatureRange> {
+        let other = other.to(self.unit());
+
+        // The hotter of the two colder ends, excluded if either excludes it.
+        let (low, low_inclusive) = if self.low > other.low {
+            (self.low, self.bounds.low_inclusive())
+        } else if other.low > self.low {
+            (other.low, other.bounds.low_inclusive())
+        } else {
+            let inclusive = self.bounds.low_inclusive() && other.bounds.low_inclusive();
+            (self.low, inclusive)
+        };
+        let (high, high_inclusive) = if self.high < other.high {
+            (self.high, self.bounds.high_inclusive())
+        } else if other.high < self.high {
+            (other.high, other.bounds.high_inclusive())
+        } else {
+            let inclusive = self.bounds.high_inclusive() && other.bounds.high_inclusive();
+            (self.high, inclusive)
+        };
+
+        let bounds = Bounds::from_ends(low_inclusive, high_inclusive);
+        TemperatureRange::new(low, high, bounds)
+            .ok()
+            .filter(|range| !range.is_empty())
+    }
+
+    /// Returns the distance from the colder end to the hotter end, in this
+    /// range's unit.
+    ///
+    /// Like any difference on the Delisle scale, the width is negative
+    /// there.
+    pub fn width(&self) -> TemperatureDelta {
+        self.high - self.low
+    }
+
+    /// Converts both ends to the requested unit.
+    ///
+    /// # Panics
+    /// Panics if either end doesn't fit when converted, like
+    /// [`Temperature::to`].
+    pub fn to(&self, unit: TemperatureUnit) -> TemperatureRange {
+        TemperatureRange {
+            low: self.low.to(unit),
+            high: self.high.to(unit),
+            bounds: self.bounds,
+        }
+    }
+
+    /// Returns the ends and whether each is included, in ascending order of
+    /// reading rather than of temperature.
+    fn ascending(&self) -> ((f64, bool), (f64, bool)) {
+        let low = (self.low.value(), self.bounds.low_inclusive());
+        let high = (self.high.value(), self.bounds.high_inclusive());
+        if runs_backwards(self.unit()) {
+            (high, low)
+        } else {
+            (low, high)
+        }
+    }
+}
+
+/// Returns true for scales whose readings fall as temperature rises.
+fn runs_backwards(unit: TemperatureUnit) -> bool {
+    unit.scale().kelvin_per_degree.numerator < 0
+}
+
+/// Writes `2–8°C` for a closed range and interval notation such as
+/// `[2, 8)°C` otherwise, in ascending order of reading.
+///
+/// Honours the same flags as `Temperature`; precision applies to both ends.
+impl fmt::Display for TemperatureRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ((first, first_inclusive), (second, second_inclusive)) = self.ascending();
+        let precision = f.precision();
+        let sign_plus = f.sign_plus();
+        let long = f.alternate();
+        let closed = self.bounds == Bounds::Closed;
+        format::pad(f, |w| {
+            if !closed {
+                w.write_char(if first_inclusive { '[' } else { '(' })?;
+            }
+            format::write_plus(w, sign_plus, first)?;
+            first.fmt_value(w, precision)?;
+            w.write_str(if closed { "\u{2013}" } else { ", " })?;
+            format::write_plus(w, sign_plus, second)?;
+            second.fmt_value(w, precision)?;
+            if !closed {
+                w.write_char(if second_inclusive { ']' } else { ')' })?;
+            }
+            format::write_unit(w, self.unit(), long, false)
+        })
+    }
+}
+
+/// Separators accepted between the ends of a closed range.
+const SEPARATORS: [&str; 5] = ["..=", "..", "\u{2013}", "\u{2014}", " to "];
+
+/// Splits `"2-8"` at a hyphen that follows a digit, so that the minus signs
+/// in `"-20--10"` are left alone.
+fn split_at_hyphen(body: &str) -> Option<(&str, &str)> {
+    let index = body.char_indices().skip(1).find_map(|(index, c)| {
+        let before = body[..index].trim_end();
+        (c == '-' && before.ends_with(|c: char| c.is_ascii_digit() || c == '.')).then_some(index)
+    })?;
+    Some((&body[..index], &body[index + 1..]))
+}
+
+/// Parses one end of a range from a slice of `input`.
+fn parse_end(input: &str, part: &str, unit: TemperatureUnit) -> Result<Temperature> {
+    Temperature::new(parse_number(input, part)?, unit)
+}
+
+/// Parses a range like `"-20..60C"`, `"2–8 °C"`, `"35 to 40 C"` or, with
+/// excluded ends, `"[2, 8) °C"`.
+///
+/// The unit comes once, after both ends, and is anything `Temperature`
+/// accepts. Apart from interval notation, both ends are included. The
+/// smaller reading comes first, even on the Delisle scale.
+impl FromStr for TemperatureRange {
+    type Err = TemperatureError;
+
+    fn from_str(input: &str) -> Result<TemperatureRange> {
+        let (span, unit) = split_unit(input)?;
+        let body = &input[span.clone()];
+        let invalid = || TemperatureError::InvalidNumber { span, source: None };
+
+        let interval = body
+            .strip_prefix(['[', '('])
+            .and_then(|inner| inner.strip_suffix([']', ')']));
+        let (first, second, bounds) = match interval {
+            Some(inner) => {
+                let (first, second) = inner.split_once(',').ok_or_else(invalid)?;
+                let bounds = Bounds::from_ends(body.starts_with('['), body.ends_with(']'));
+                (first, second, bounds)
+            }
+            None => {
+                let (first, second) = SEPARATORS
+                    .into_iter()
+                    .find_map(|separator| body.split_once(separator))
+                    .or_else(|| split_at_hyphen(body))
+                    .ok_or_else(invalid)?;
+                (first, second, Bounds::Closed)
+            }
+        };
+
+        let first = parse_end(input, first, unit)?;
+        let second = parse_end(input, second, unit)?;
+        if runs_backwards(unit) {
+            let bounds = Bounds::from_ends(bounds.high_inclusive(), bounds.low_inclusive());
+            TemperatureRange::new(second, first, bounds)
+        } else {
+            TemperatureRange::new(first, second, bounds)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, TemperatureRange};
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+
+    fn celsius(value: f64) -> Temperature {
+        Temperature::new(value, TemperatureUnit::Celsius).expect("valid")
+    }
+
+    fn range(input: &str) -> TemperatureRange {
+        input.parse().expect(input)
+    }
+
+    #[test]
+    fn parses_spec_strings() {
+        let expected = TemperatureRange::inclusive(celsius(-20.0), celsius(60.0)).expect("valid");
+        for text in [
+            "-20..60C",
+            "-20..=60 \u{00B0}C",
+            "-20 \u{2013} 60 \u{00B0}C",
+            "-20 to 60 C",
+        ] {
+            assert_eq!(range(text), expected, "{text}");
+        }
+        assert_eq!(range("2-8 \u{00B0}C"), range("2\u{2013}8 \u{00B0}C"));
+        assert_eq!(range("-20--10C").high(), celsius(-10.0));
+        assert_eq!(range("[2, 8) \u{00B0}C").bounds(), Bounds::ClosedOpen);
+
+        let err = "60..-20C".parse::<TemperatureRange>().unwrap_err();
+        assert!(matches!(err, TemperatureError::InvertedRange { .. }));
+        assert_eq!(
+            "2..x C".parse::<TemperatureRange>().unwrap_err().span(),
+            Some(3..4)
+        );
+        assert!("25C".parse::<TemperatureRange>().is_err());
+    }
+
+    #[test]
+    fn contains_respects_bounds() {
+        let closed = range("2..8C");
+        assert!(closed.contains(celsius(2.0)));
+        assert!(closed.contains(celsius(8.0)));
+        assert!(
+            closed.contains(Temperature::new(40.0, TemperatureUnit::Fahrenheit).expect("valid"))
+        );
+        assert!(!closed.contains(celsius(8.5)));
+
+        let half_open = range("[2, 8)C");
+        assert!(half_open.contains(celsius(2.0)));
+        assert!(!half_open.contains(celsius(8.0)));
+    }
+
+    #[test]
+    fn intersection_and_overlap() {
+        let operating = range("-20..60C");
+        let fridge = range("35.6..46.4F");
+        let common = operating.intersection(&fridge).expect("overlaps");
+        assert_eq!(common.unit(), TemperatureUnit::Celsius);
+        assert_eq!(common, range("2..8C"));
+
+        assert!(range("[0, 10)C").overlaps(&range("5..20C")));
+        assert!(!range("[0, 10)C").overlaps(&range("10..20C")));
+        assert!(range("0..10C").overlaps(&range("10..20C")));
+        assert_eq!(
+            range("(0, 10]C").intersection(&range("[10, 20)C")),
+            Some(range("10..10C"))
+        );
+    }
+
+    #[test]
+    fn width_and_conversion() {
+        let operating = range("-20..60C");
+        assert_eq!(operating.width().value(), 80.0);
+        assert_eq!(
+            operating.to(TemperatureUnit::Fahrenheit).width().value(),
+            144.0
+        );
+
+        let delisle = range("[0, 150)De");
+        assert_eq!(delisle.low().to(TemperatureUnit::Celsius).value(), 0.0);
+        assert_eq!(delisle.bounds(), Bounds::OpenClosed);
+        assert_eq!(format!("{delisle}"), "[0, 150)\u{00B0}De");
+        assert_eq!(
+            format!("{}", range("[0, 100)C").to(TemperatureUnit::Delisle)),
+            "(0, 150]\u{00B0}De"
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for text in ["2\u{2013}8\u{00B0}C", "[-40, 85)\u{00B0}F", "(250, 300)K"] {
+            assert_eq!(format!("{}", range(text)), text);
+        }
+        assert_eq!(format!("{:.1}", range("2..8C")), "2.0\u{2013}8.0\u{00B0}C");
+        assert_eq!(
+            format!("{:#}", range("2..8C")),
+            "2\u{2013}8 degrees Celsius"
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! A catalog of named reference temperatures.
+//!
+//! The catalog holds the defining fixed points of the International
+//! Temperature Scale of 1990 (ITS-90), from the triple point of hydrogen to
+//! the freezing point of copper, alongside everyday and scientific
+//! landmarks such as body temperature, liquid nitrogen and the surface of
+//! the sun. Entries are found by name or alias with [`lookup`], or by
+//! temperature with [`nearest`].
+//!
+//! Boiling points are at standard atmospheric pressure.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::reference;
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! let water = reference::lookup("triple point of water").unwrap();
+//! assert_eq!(water.temperature().value(), 273.16);
+//! assert!(water.is_its90_fixed_point());
+//!
+//! let ln2 = reference::lookup("LN2").unwrap();
+//! assert_eq!(ln2.name(), "boiling point of nitrogen");
+//!
+//! let fever = Temperature::new(38.5, TemperatureUnit::Celsius).unwrap();
+//! assert_eq!(reference::nearest(fever).name(), "body temperature");
+//! ```
+
+use crate::temperature::{
+    Temperature, TemperatureUnit, ABSOLUTE_ZERO, BOILING_POINT, FREEZING_POINT,
+};
+
+/// A named reference temperature.
+#[derive(Debug)]
+pub struct Reference {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    temperature: Temperature,
+    its90: bool,
+}
+
+impl Reference {
+    const fn new(
+        name: &'static str,
+        aliases: &'static [&'static str],
+        temperature: Temperature,
+    ) -> Reference {
+        Reference {
+            name,
+            aliases,
+            temperature,
+            its90: false,
+        }
+    }
+
+    /// An ITS-90 defining fixed point, given in kelvin.
+    const fn fixed_point(
+        name: &'static str,
+        aliases: &'static [&'static str],
+        kelvin: f64,
+    ) -> Reference {
+        Reference {
+            name,
+            aliases,
+            temperature: Temperature::new_const(kelvin, TemperatureUnit::Kelvin),
+            its90: true,
+        }
+    }
+
+    /// Returns the canonical name, like `"triple point of water"`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the other names this entry is found by, like `"LN2"`.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns the temperature, in the unit it is defined in.
+    pub fn temperature(&self) -> Temperature {
+        self.temperature
+    }
+
+    /// Returns true for the defining fixed points of ITS-90.
+    pub fn is_its90_fixed_point(&self) -> bool {
+        self.its90
+    }
+}
+
+/// Every reference temperature, coldest first.
+#[rustfmt::skip]
+static CATALOG: [Reference; 25] = [
+    Reference::new("absolute zero", &["zero kelvin"], ABSOLUTE_ZERO),
+    Reference::new(
+        "cosmic microwave background",
+        &["CMB"],
+        Temperature::new_const(2.725_48, TemperatureUnit::Kelvin),
+    ),
+    Reference::new(
+        "boiling point of helium",
+        &["liquid helium", "LHe"],
+        Temperature::new_const(4.222, TemperatureUnit::Kelvin),
+    ),
+    Reference::fixed_point("triple point of hydrogen", &["hydrogen triple point"], 13.8033),
+    Reference::fixed_point("triple point of neon", &["neon triple point"], 24.5561),
+    Reference::fixed_point("triple point of oxygen", &["oxygen triple point"], 54.3584),
+    Reference::new(
+        "boiling point of nitrogen",
+        &["liquid nitrogen", "LN2"],
+        Temperature::new_const(77.355, TemperatureUnit::Kelvin),
+    ),
+    Reference::fixed_point("triple point of argon", &["argon triple point"], 83.8058),
+    Reference::new(
+        "sublimation point of dry ice",
+        &["dry ice", "dry ice sublimation"],
+        Temperature::new_const(-78.464, TemperatureUnit::Celsius),
+    ),
+    Reference::fixed_point("triple point of mercury", &["mercury triple point"], 234.3156),
+    Reference::new(
+        "freezing point of water",
+        &["freezing point", "ice point", "melting point of ice"],
+        FREEZING_POINT,
+    ),
+    Reference::fixed_point("triple point of water", &["water triple point", "TPW"], 273.16),
+    Reference::new(
+        "room temperature",
+        &[],
+        Temperature::new_const(20.0, TemperatureUnit::Celsius),
+    ),
+    Reference::new(
+        "standard ambient temperature",
+        &["SATP"],
+        Temperature::new_const(25.0, TemperatureUnit::Celsius),
+    ),
+    Reference::fixed_point(
+        "melting point of gallium",
+        &["gallium melting point", "gallium point"],
+        302.9146,
+    ),
+    Reference::new(
+        "body temperature",
+        &["normal body temperature", "body temp"],
+        Temperature::new_const(37.0, TemperatureUnit::Celsius),
+    ),
+    Reference::new(
+        "boiling point of water",
+        &["boiling point", "steam point"],
+        BOILING_POINT,
+    ),
+    Reference::fixed_point("freezing point of indium", &["indium point"], 429.7485),
+    Reference::fixed_point("freezing point of tin", &["tin point"], 505.078),
+    Reference::fixed_point("freezing point of zinc", &["zinc point"], 692.677),
+    Reference::fixed_point(
+        "freezing point of aluminium",
+        &["freezing point of aluminum", "aluminium point", "aluminum point"],
+        933.473,
+    ),
+    Reference::fixed_point("freezing point of silver", &["silver point"], 1234.93),
+    Reference::fixed_point("freezing point of gold", &["gold point"], 1337.33),
+    Reference::fixed_point("freezing point of copper", &["copper point"], 1357.77),
+    Reference::new(
+        "surface of the sun",
+        &["sun", "solar surface", "photosphere"],
+        Temperature::new_const(5772.0, TemperatureUnit::Kelvin),
+    ),
+];
+
+/// Returns every reference temperature, coldest first.
+pub fn all() -> &'static [Reference] {
+    &CATALOG
+}
+
+/// Compares two names, ignoring case, spaces and punctuation, so that
+/// `"Triple-point of water"` matches `"triple point of water"`.
+fn same_name(a: &str, b: &str) -> bool {
+    a.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .eq(b
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase))
+}
+
+/// Looks up a reference temperature by its name or an alias.
+pub fn lookup(name: &str) -> Option<&'static Reference> {
+    CATALOG.iter().find(|reference| {
+        same_name(reference.name, name)
+            || reference.aliases.iter().any(|alias| same_name(alias, name))
+    })
+}
+
+/// Returns the reference temperature closest to `temp`.
+pub fn nearest(temp: Temperature) -> &'static Reference {
+    let kelvin = |reference: &Reference| reference.temperature.to(TemperatureUnit::Kelvin).value();
+    let Some(target) = temp.checked_to(TemperatureUnit::Kelvin) else {
+        // Too hot to express in kelvin, so closest to the hottest reference,
+        // which ends the sorted catalog.
+        return &CATALOG[CATALOG.len() - 1];
+    };
+    let distance = |reference: &Reference| (kelvin(reference) - target.value()).abs();
+    CATALOG
+        .iter()
+        .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        .unwrap_or(&CATALOG[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all, lookup, nearest};
+    use crate::temperature::{Temperature, TemperatureUnit};
+
+    #[test]
+    fn catalog_is_sorted_and_has_the_its90_points() {
+        let kelvin: Vec<f64> = all()
+            .iter()
+            .map(|reference| reference.temperature().to(TemperatureUnit::Kelvin).value())
+            .collect();
+        assert!(
+            kelvin.windows(2).all(|pair| pair[0] < pair[1]),
+            "{kelvin:?}"
+        );
+        assert_eq!(
+            all()
+                .iter()
+                .filter(|reference| reference.is_its90_fixed_point())
+                .count(),
+            14
+        );
+    }
+
+    #[test]
+    fn looks_up_names_and_aliases() {
+        assert_eq!(
+            lookup("LN2").map(|r| r.name()),
+            Some("boiling point of nitrogen")
+        );
+        assert_eq!(
+            lookup("ln2").map(|r| r.name()),
+            Some("boiling point of nitrogen")
+        );
+        assert_eq!(
+            lookup("Triple-Point of Water").map(|r| r.name()),
+            Some("triple point of water")
+        );
+        assert_eq!(
+            lookup("freezing point of aluminum").map(|r| r.name()),
+            Some("freezing point of aluminium")
+        );
+        let dry_ice = lookup("dry ice").expect("known");
+        let kelvin = dry_ice.temperature().to(TemperatureUnit::Kelvin).value();
+        assert!((kelvin - 194.686).abs() < 1e-9);
+        assert!(lookup("planck temperature").is_none());
+
+        for reference in all() {
+            assert!(std::ptr::eq(
+                lookup(reference.name()).expect("known"),
+                reference
+            ));
+            for alias in reference.aliases() {
+                assert!(
+                    std::ptr::eq(lookup(alias).expect("known"), reference),
+                    "{alias}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finds_the_nearest_landmark() {
+        let celsius = |value| Temperature::new(value, TemperatureUnit::Celsius).expect("valid");
+        assert_eq!(nearest(celsius(38.5)).name(), "body temperature");
+        assert_eq!(nearest(celsius(21.0)).name(), "room temperature");
+        assert_eq!(nearest(celsius(0.004)).name(), "freezing point of water");
+        assert_eq!(nearest(celsius(1_000_000.0)).name(), "surface of the sun");
+        let cold = Temperature::new(1.0, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(nearest(cold).name(), "absolute zero");
+        let huge = Temperature::new(f64::MAX, TemperatureUnit::Newton).expect("valid");
+        assert_eq!(nearest(huge).name(), "surface of the sun");
+    }
+}
@@ -0,0 +1,282 @@
+//! Definitions of the supported temperature scales.
+//!
+//! Every scale is described relative to kelvin by two exact rationals: the
+//! size of one degree in kelvin, and the reading of absolute zero on that
+//! scale. A reading `v` is then `(v - absolute_zero) * kelvin_per_degree`
+//! kelvin, and the conversion between any two scales is a single affine
+//! transform derived exactly from their two table entries.
+//!
+//! Adding a scale means adding a `TemperatureUnit` variant and one entry to
+//! `SCALES`. The entry also carries the scale's UCUM and UDUNITS codes, used
+//! by the `codes` module.
+
+use crate::temperature::TemperatureUnit;
+
+/// An exact rational number `numerator / denominator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Ratio {
+    pub(crate) numerator: i64,
+    pub(crate) denominator: i64,
+}
+
+impl Ratio {
+    const fn new(numerator: i64, denominator: i64) -> Ratio {
+        Ratio {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Returns the ratio in lowest terms with a positive denominator.
+    const fn reduced(self) -> Ratio {
+        let divisor = gcd(self.numerator, self.denominator) * self.denominator.signum();
+        Ratio::new(self.numerator / divisor, self.denominator / divisor)
+    }
+
+    const fn mul(self, other: Ratio) -> Ratio {
+        Ratio::new(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+        .reduced()
+    }
+
+    const fn div(self, other: Ratio) -> Ratio {
+        Ratio::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+        .reduced()
+    }
+
+    const fn sub(self, other: Ratio) -> Ratio {
+        Ratio::new(
+            self.numerator * other.denominator - other.numerator * self.denominator,
+            self.denominator * other.denominator,
+        )
+        .reduced()
+    }
+
+    /// Returns the nearest `f64` to this ratio.
+    pub(crate) const fn to_f64(self) -> f64 {
+        // Both parts are exactly representable, so the division rounds once.
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+const fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// The definition of a temperature scale.
+#[derive(Debug)]
+pub(crate) struct Scale {
+    /// Long-form name, e.g. `Celsius`.
+    pub(crate) name: &'static str,
+    /// Abbreviation shown after the degree sign, e.g. `C`.
+    pub(crate) symbol: &'static str,
+    /// Whether readings are written with a degree sign (`°C`, but `K`).
+    pub(crate) degree_sign: bool,
+    /// The size of one degree in kelvin. Negative for scales that run
+    /// backwards, like Delisle.
+    pub(crate) kelvin_per_degree: Ratio,
+    /// The reading of absolute zero on this scale.
+    pub(crate) absolute_zero: Ratio,
+    /// Case-sensitive UCUM code, if UCUM defines one.
+    pub(crate) ucum: Option<&'static str>,
+    /// UDUNITS-2 symbol, as used in CF metadata, if UDUNITS defines one.
+    pub(crate) udunits: Option<&'static str>,
+}
+
+/// All supported scales, in `TemperatureUnit` declaration order.
+pub(crate) const SCALES: [Scale; 8] = [
+    Scale {
+        name: "Celsius",
+        symbol: "C",
+        degree_sign: true,
+        kelvin_per_degree: Ratio::new(1, 1),
+        absolute_zero: Ratio::new(-27_315, 100),
+        ucum: Some("Cel"),
+        udunits: Some("degC"),
+    },
+    Scale {
+        name: "Fahrenheit",
+        symbol: "F",
+        degree_sign: true,
+        kelvin_per_degree: Ratio::new(5, 9),
+        absolute_zero: Ratio::new(-45_967, 100),
+        ucum: Some("[degF]"),
+        udunits: Some("degF"),
+    },
+    Scale {
+        name: "kelvin",
+        symbol: "K",
+        degree_sign: false,
+        kelvin_per_degree: Ratio::new(1, 1),
+        absolute_zero: Ratio::new(0, 1),
+        ucum: Some("K"),
+        udunits: Some("K"),
+    },
+    Scale {
+        name: "Rankine",
+        symbol: "Ra",
+        degree_sign: true,
+        kelvin_per_degree: Ratio::new(5, 9),
+        absolute_zero: Ratio::new(0, 1),
+        ucum: Some("[degR]"),
+        udunits: Some("degR"),
+    },
+    Scale {
+        name: "R\u{00E9}aumur",
+        symbol: "R\u{00E9}",
+        degree_sign: true,
+        kelvin_per_degree: Ratio::new(5, 4),
+        absolute_zero: Ratio::new(-21_852, 100),
+        ucum: Some("[degRe]"),
+        udunits: None,
+    },
+    Scale {
+        name: "Delisle",
+        symbol: "De",
+        degree_sign: true,
+        kelvin_per_degree: Ratio::new(-2, 3),
+        absolute_zero: Ratio::new(559_725, 1_000),
+        ucum: None,
+        udunits: None,
+    },
+    Scale {
+        name: "Newton",
+        symbol: "N",
+        degree_sign: true,
+        kelvin_per_degree: Ratio::new(100, 33),
+        absolute_zero: Ratio::new(-901_395, 10_000),
+        ucum: None,
+        udunits: None,
+    },
+    Scale {
+        name: "R\u{00F8}mer",
+        symbol: "R\u{00F8}",
+        degree_sign: true,
+        kelvin_per_degree: Ratio::new(40, 21),
+        absolute_zero: Ratio::new(-13_590_375, 100_000),
+        ucum: None,
+        udunits: None,
+    },
+];
+
+/// The affine transform between two scales, written over a common
+/// denominator as `to = (from * scale + offset) / divisor`.
+///
+/// The three coefficients are small integers derived exactly from the scale
+/// table, so whole-number readings like `100°C` convert without rounding.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Affine {
+    pub(crate) scale: f64,
+    pub(crate) offset: f64,
+    pub(crate) divisor: f64,
+}
+
+impl Affine {
+    /// Derives the transform from `from` readings to `to` readings.
+    pub(crate) const fn between(from: TemperatureUnit, to: TemperatureUnit) -> Affine {
+        let (scale, offset, divisor) = exact_affine(from, to);
+        Affine {
+            scale: scale as f64,
+            offset: offset as f64,
+            divisor: divisor as f64,
+        }
+    }
+
+    /// Applies the transform to a reading.
+    pub(crate) const fn apply(&self, value: f64) -> f64 {
+        let result = (value * self.scale + self.offset) / self.divisor;
+        if result.is_finite() || !value.is_finite() {
+            result
+        } else {
+            // Near f64::MAX the product overflows even when the result
+            // doesn't, so divide first at the cost of a rounding step.
+            value * (self.scale / self.divisor) + self.offset / self.divisor
+        }
+    }
+}
+
+/// The integer coefficients `(scale, offset, divisor)` of the transform from
+/// `from` readings to `to` readings.
+pub(crate) const fn exact_affine(from: TemperatureUnit, to: TemperatureUnit) -> (i64, i64, i64) {
+    // to = (from - from_zero) * ratio + to_zero = from * ratio + offset
+    let ratio = degree_ratio(from, to);
+    let from = from.scale();
+    let to = to.scale();
+    let offset = to.absolute_zero.sub(from.absolute_zero.mul(ratio));
+
+    let divisor =
+        ratio.denominator / gcd(ratio.denominator, offset.denominator) * offset.denominator;
+    (
+        ratio.numerator * (divisor / ratio.denominator),
+        offset.numerator * (divisor / offset.denominator),
+        divisor,
+    )
+}
+
+/// The size of one `from` degree in `to` degrees, in lowest terms.
+pub(crate) const fn degree_ratio(from: TemperatureUnit, to: TemperatureUnit) -> Ratio {
+    from.scale()
+        .kelvin_per_degree
+        .div(to.scale().kelvin_per_degree)
+}
+
+impl TemperatureUnit {
+    /// Returns the table entry for this unit.
+    pub(crate) const fn scale(self) -> &'static Scale {
+        &SCALES[self as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{exact_affine, Affine, SCALES};
+    use crate::temperature::TemperatureUnit;
+
+    #[test]
+    fn table_is_in_declaration_order() {
+        assert_eq!(SCALES.len(), TemperatureUnit::ALL.len());
+        for unit in TemperatureUnit::ALL {
+            let symbol = unit.scale().symbol;
+            assert_eq!(
+                TemperatureUnit::from_abbreviation(symbol),
+                Some(unit),
+                "{symbol}"
+            );
+        }
+    }
+
+    #[test]
+    fn every_scale_maps_absolute_zero_to_zero_kelvin() {
+        for unit in TemperatureUnit::ALL {
+            let zero = unit.scale().absolute_zero.to_f64();
+            assert_eq!(
+                Affine::between(unit, TemperatureUnit::Kelvin).apply(zero),
+                0.0
+            );
+        }
+    }
+
+    #[test]
+    fn coefficients_are_small_integers() {
+        assert_eq!(
+            exact_affine(TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit),
+            (9, 160, 5)
+        );
+        assert_eq!(
+            exact_affine(TemperatureUnit::Celsius, TemperatureUnit::Romer),
+            (21, 300, 40)
+        );
+        assert_eq!(
+            exact_affine(TemperatureUnit::Kelvin, TemperatureUnit::Kelvin),
+            (1, 0, 1)
+        );
+    }
+}
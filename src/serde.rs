@@ -0,0 +1,359 @@
+//! Serde support, behind the `serde` feature.
+//!
+//! `TemperatureUnit` serializes as its lowercase name, like `"celsius"`.
+//! `Temperature` serializes in the structured form
+//! `{ "value": 37.5, "unit": "celsius" }`. Human-readable formats like JSON
+//! deserialize it from either that or the compact string form `"37.5C"`;
+//! binary formats like bincode, which can't tell the two apart, expect the
+//! structured form they wrote. Both forms go through `Temperature::new`, so
+//! values below absolute zero are rejected.
+//!
+//! To choose one form for a field, use the `compact` or `structured` module
+//! with `#[serde(with = "...")]`.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! let temp: Temperature = serde_json::from_str(r#""37.5C""#).unwrap();
+//! assert_eq!(temp.unit(), TemperatureUnit::Celsius);
+//!
+//! let json = serde_json::to_string(&temp).unwrap();
+//! assert_eq!(json, r#"{"value":37.5,"unit":"celsius"}"#);
+//!
+//! assert!(serde_json::from_str::<Temperature>(r#""-300C""#).is_err());
+//! ```
+//!
+//! ```rust
+//! use convert_temp::Temperature;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading {
+//!     #[serde(with = "convert_temp::serde::compact")]
+//!     temperature: Temperature,
+//! }
+//!
+//! let reading: Reading = serde_json::from_str(r#"{"temperature":"98.6F"}"#).unwrap();
+//! let json = serde_json::to_string(&reading).unwrap();
+//! assert_eq!(json, r#"{"temperature":"98.6F"}"#);
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::temperature::{Temperature, TemperatureUnit};
+
+const FIELDS: &[&str] = &["value", "unit"];
+
+impl TemperatureUnit {
+    /// The name used by the serde impls, e.g. `celsius`.
+    fn serde_name(&self) -> &'static str {
+        match *self {
+            TemperatureUnit::Celsius => "celsius",
+            TemperatureUnit::Fahrenheit => "fahrenheit",
+            TemperatureUnit::Kelvin => "kelvin",
+            TemperatureUnit::Rankine => "rankine",
+            TemperatureUnit::Reaumur => "reaumur",
+            TemperatureUnit::Delisle => "delisle",
+            TemperatureUnit::Newton => "newton",
+            TemperatureUnit::Romer => "romer",
+        }
+    }
+}
+
+impl Serialize for TemperatureUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.serde_name())
+    }
+}
+
+/// Accepts the lowercase names, as serialized, or abbreviations like `"C"`.
+impl<'de> Deserialize<'de> for TemperatureUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct UnitVisitor;
+
+        impl Visitor<'_> for UnitVisitor {
+            type Value = TemperatureUnit;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a temperature unit name or abbreviation")
+            }
+
+            fn visit_str<E: de::Error>(self, input: &str) -> Result<TemperatureUnit, E> {
+                TemperatureUnit::ALL
+                    .into_iter()
+                    .find(|unit| unit.serde_name() == input)
+                    .or_else(|| TemperatureUnit::from_abbreviation(input))
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(input), &self))
+            }
+        }
+
+        deserializer.deserialize_str(UnitVisitor)
+    }
+}
+
+/// Serializes in the structured form.
+impl Serialize for Temperature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        structured::serialize(self, serializer)
+    }
+}
+
+/// Deserializes from either the compact or the structured form in
+/// human-readable formats, and from the structured form otherwise.
+impl<'de> Deserialize<'de> for Temperature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(TemperatureVisitor {
+                compact: true,
+                structured: true,
+            })
+        } else {
+            structured::deserialize(deserializer)
+        }
+    }
+}
+
+struct TemperatureVisitor {
+    compact: bool,
+    structured: bool,
+}
+
+impl TemperatureVisitor {
+    fn validate<E: de::Error>(value: f64, unit: TemperatureUnit) -> Result<Temperature, E> {
+        Temperature::new(value, unit).map_err(E::custom)
+    }
+}
+
+impl<'de> Visitor<'de> for TemperatureVisitor {
+    type Value = Temperature;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.compact, self.structured) {
+            (true, true) => write!(f, "a temperature string like \"37.5C\" or a value and unit"),
+            (true, false) => write!(f, "a temperature string like \"37.5C\""),
+            _ => write!(f, "a temperature value and unit"),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, input: &str) -> Result<Temperature, E> {
+        if !self.compact {
+            return Err(E::invalid_type(de::Unexpected::Str(input), &self));
+        }
+        input.parse().map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Temperature, A::Error> {
+        if !self.structured {
+            return Err(de::Error::invalid_type(de::Unexpected::Seq, &self));
+        }
+        let value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let unit = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::validate(value, unit)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Temperature, A::Error> {
+        if !self.structured {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+        let mut value = None;
+        let mut unit = None;
+        while let Some(key) = map.next_key::<Field>()? {
+            match key {
+                Field::Value if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                Field::Unit if unit.is_some() => return Err(de::Error::duplicate_field("unit")),
+                Field::Value => value = Some(map.next_value()?),
+                Field::Unit => unit = Some(map.next_value()?),
+            }
+        }
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+        Self::validate(value, unit)
+    }
+}
+
+enum Field {
+    Value,
+    Unit,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "`value` or `unit`")
+            }
+
+            fn visit_str<E: de::Error>(self, input: &str) -> Result<Field, E> {
+                match input {
+                    "value" => Ok(Field::Value),
+                    "unit" => Ok(Field::Unit),
+                    _ => Err(E::unknown_field(input, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// The compact string form, `"37.5C"`, for use with `#[serde(with)]`.
+pub mod compact {
+    use core::fmt;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::TemperatureVisitor;
+    use crate::temperature::Temperature;
+
+    /// Writes the value and abbreviation with no degree sign, which is the
+    /// form `Temperature::from_str` parses.
+    struct Compact<'a>(&'a Temperature);
+
+    impl fmt::Display for Compact<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}{}", self.0.value(), self.0.unit().scale().symbol)
+        }
+    }
+
+    /// Serializes a temperature as a string like `"37.5C"`.
+    pub fn serialize<S: Serializer>(temp: &Temperature, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Compact(temp))
+    }
+
+    /// Deserializes a temperature from a string like `"37.5C"`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Temperature, D::Error> {
+        deserializer.deserialize_str(TemperatureVisitor {
+            compact: true,
+            structured: false,
+        })
+    }
+}
+
+/// The structured form, `{ "value": 37.5, "unit": "celsius" }`, for use with
+/// `#[serde(with)]`.
+pub mod structured {
+    use ::serde::ser::SerializeStruct;
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{TemperatureVisitor, FIELDS};
+    use crate::temperature::Temperature;
+
+    /// Serializes a temperature as a value and unit.
+    pub fn serialize<S: Serializer>(temp: &Temperature, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Temperature", 2)?;
+        state.serialize_field("value", &temp.value())?;
+        state.serialize_field("unit", &temp.unit())?;
+        state.end()
+    }
+
+    /// Deserializes a temperature from a value and unit.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Temperature, D::Error> {
+        deserializer.deserialize_struct(
+            "Temperature",
+            FIELDS,
+            TemperatureVisitor {
+                compact: false,
+                structured: true,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::temperature::{Temperature, TemperatureUnit};
+
+    #[test]
+    fn unit_round_trips_by_name() {
+        for unit in TemperatureUnit::ALL {
+            let json = serde_json::to_string(&unit).expect("serializes");
+            let back: TemperatureUnit = serde_json::from_str(&json).expect("deserializes");
+            assert_eq!(back, unit);
+        }
+        assert_eq!(
+            serde_json::to_string(&TemperatureUnit::Reaumur).unwrap(),
+            r#""reaumur""#
+        );
+        let unit: TemperatureUnit = serde_json::from_str(r#""F""#).expect("abbreviation");
+        assert_eq!(unit, TemperatureUnit::Fahrenheit);
+        assert!(serde_json::from_str::<TemperatureUnit>(r#""furlong""#).is_err());
+    }
+
+    #[test]
+    fn temperature_accepts_both_forms() {
+        let compact: Temperature = serde_json::from_str(r#""80Re""#).expect("compact");
+        let structured: Temperature =
+            serde_json::from_str(r#"{"unit":"reaumur","value":80}"#).expect("structured");
+        let sequence: Temperature = serde_json::from_str(r#"[80, "reaumur"]"#).expect("sequence");
+        for temp in [compact, structured, sequence] {
+            assert_eq!(temp.value(), 80.0);
+            assert_eq!(temp.unit(), TemperatureUnit::Reaumur);
+        }
+    }
+
+    #[test]
+    fn temperature_round_trips_through_binary_formats() {
+        for unit in TemperatureUnit::ALL {
+            let temp = Temperature::new(451.0, unit).expect("valid");
+            let bytes = bincode::serialize(&temp).expect("serializes");
+            let back: Temperature = bincode::deserialize(&bytes).expect("deserializes");
+            assert_eq!(back.value(), 451.0);
+            assert_eq!(back.unit(), unit);
+        }
+
+        let cold = Temperature::new_unchecked(-1.0, TemperatureUnit::Kelvin);
+        let bytes = bincode::serialize(&cold).expect("serializes");
+        assert!(bincode::deserialize::<Temperature>(&bytes).is_err());
+    }
+
+    #[test]
+    fn temperature_rejects_below_absolute_zero() {
+        assert!(serde_json::from_str::<Temperature>(r#"{"value":-1,"unit":"kelvin"}"#).is_err());
+        assert!(serde_json::from_str::<Temperature>(r#"[-500, "celsius"]"#).is_err());
+        assert!(serde_json::from_str::<Temperature>(r#""-500C""#).is_err());
+    }
+
+    #[test]
+    fn structured_rejects_bad_fields() {
+        assert!(serde_json::from_str::<Temperature>(r#"{"value":1}"#).is_err());
+        assert!(
+            serde_json::from_str::<Temperature>(r#"{"value":1,"unit":"kelvin","x":2}"#).is_err()
+        );
+        assert!(
+            serde_json::from_str::<Temperature>(r#"{"value":1,"value":2,"unit":"kelvin"}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn with_modules_select_a_form() {
+        let temp = Temperature::new(671.67, TemperatureUnit::Rankine).expect("valid");
+
+        let mut json = Vec::new();
+        let mut serializer = serde_json::Serializer::new(&mut json);
+        super::compact::serialize(&temp, &mut serializer).expect("serializes");
+        assert_eq!(String::from_utf8(json).unwrap(), r#""671.67Ra""#);
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#"{"value":1,"unit":"kelvin"}"#);
+        assert!(super::compact::deserialize(&mut deserializer).is_err());
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#""1K""#);
+        assert!(super::structured::deserialize(&mut deserializer).is_err());
+    }
+}
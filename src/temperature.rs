@@ -1,6 +1,7 @@
 //! Temperature conversion types and helpers.
 //!
-//! Provides a `Temperature` struct, conversion between `C`, `F`, and `K`,
+//! Provides a `Temperature` struct, conversion between `C`, `F`, `K`, `Ra`,
+//! `Ré`, `De`, `N` and `Rø`,
 //! common constants, parsing from strings, and display formatting.
 //!
 //! # Examples
@@ -45,255 +46,516 @@
 // temp_fahrenheit = to_fahrenheit(temp_celsius)
 // converted_temp = convert_temp(temp) temp: 37.5C or 99.5F
 
-use std::fmt;
-use std::str::FromStr;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Range;
+use core::str::FromStr;
+
+use crate::delta::TemperatureDelta;
+use crate::format;
+use crate::numeric::Scalar;
+use crate::scale::Affine;
+use crate::validate::{AbsoluteZero, Validator};
 
 /// Temperature units.
-#[derive(Debug, PartialEq, Eq, Clone, Copy)] 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TemperatureUnit {
     Celsius,
     Fahrenheit,
     Kelvin,
+    Rankine,
+    Reaumur,
+    Delisle,
+    Newton,
+    Romer,
 }
 
 impl TemperatureUnit {
+    /// Every supported unit, in declaration order.
+    pub const ALL: [TemperatureUnit; 8] = [
+        TemperatureUnit::Celsius,
+        TemperatureUnit::Fahrenheit,
+        TemperatureUnit::Kelvin,
+        TemperatureUnit::Rankine,
+        TemperatureUnit::Reaumur,
+        TemperatureUnit::Delisle,
+        TemperatureUnit::Newton,
+        TemperatureUnit::Romer,
+    ];
+
     /// Returns the long-form name of the unit.
-    #[allow(dead_code)]
     fn description(&self) -> &str {
-        match *self {
-            TemperatureUnit::Celsius => "Celsius",
-            TemperatureUnit::Fahrenheit => "Fahrenheit",
-            TemperatureUnit::Kelvin => "kelvin",
-        }
+        self.scale().name
     }
 
-    /// Returns the one-letter abbreviation for the unit.
+    /// Returns the abbreviation for the unit, as shown after the degree sign.
     fn abbreviation(&self) -> &str {
-        match *self {
-            TemperatureUnit::Celsius => "C",
-            TemperatureUnit::Fahrenheit => "F",
-            TemperatureUnit::Kelvin => "K",
-        }
+        self.scale().symbol
     }
-}
 
-/// Result alias for temperature creation failures.
-pub type Result<T> = std::result::Result<T, InvalidTemperature>;
-
-/// Error returned when a temperature is below absolute zero.
-#[derive(Debug, Clone)]
-pub struct InvalidTemperature;
+    /// Looks up a unit by its abbreviation.
+    ///
+    /// Accepts the `Display` abbreviations as well as ASCII spellings for the
+    /// scales whose symbols are not plain letters: `C`, `F`, `K`, `R`/`Ra`,
+    /// `Re`/`Ré`, `De`, `N` and `Ro`/`Rø`.
+    pub fn from_abbreviation(abbreviation: &str) -> Option<TemperatureUnit> {
+        match abbreviation {
+            "R" => Some(TemperatureUnit::Rankine),
+            "Re" => Some(TemperatureUnit::Reaumur),
+            "Ro" => Some(TemperatureUnit::Romer),
+            _ => TemperatureUnit::ALL
+                .into_iter()
+                .find(|unit| unit.abbreviation() == abbreviation),
+        }
+    }
 
-impl fmt::Display for InvalidTemperature {
-    fn fmt(&self, f: &mut fmt:: Formatter) -> fmt::Result {
-        write!(f, "Temperature less than 0.0k")
+    /// Looks up a unit by abbreviation, name or Unicode symbol, ignoring
+    /// case.
+    ///
+    /// Accepts everything `from_abbreviation` does, plus names like
+    /// `"kelvin"` or `"Réaumur"` and the symbols `℃`, `℉` and `K` (KELVIN
+    /// SIGN).
+    pub fn from_name(name: &str) -> Option<TemperatureUnit> {
+        UNIT_NAMES
+            .into_iter()
+            .find(|(spelling, _)| eq_ignore_case(spelling, name))
+            .map(|(_, unit)| unit)
     }
 }
 
-/// Errors that can occur when parsing a temperature from a string.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum TemperatureParseError {
-    Empty,
-    MissingUnit,
-    InvalidUnit(char),
-    InvalidNumber,
-    BelowAbsoluteZero,
+/// Every spelling `TemperatureUnit::from_name` accepts. Matching ignores
+/// case, and KELVIN SIGN lowercases to `k`, so it needs no entry of its own.
+const UNIT_NAMES: [(&str, TemperatureUnit); 25] = [
+    ("C", TemperatureUnit::Celsius),
+    ("\u{2103}", TemperatureUnit::Celsius),
+    ("celsius", TemperatureUnit::Celsius),
+    ("centigrade", TemperatureUnit::Celsius),
+    ("F", TemperatureUnit::Fahrenheit),
+    ("\u{2109}", TemperatureUnit::Fahrenheit),
+    ("fahrenheit", TemperatureUnit::Fahrenheit),
+    ("K", TemperatureUnit::Kelvin),
+    ("kelvin", TemperatureUnit::Kelvin),
+    ("kelvins", TemperatureUnit::Kelvin),
+    ("R", TemperatureUnit::Rankine),
+    ("Ra", TemperatureUnit::Rankine),
+    ("rankine", TemperatureUnit::Rankine),
+    ("Re", TemperatureUnit::Reaumur),
+    ("R\u{00E9}", TemperatureUnit::Reaumur),
+    ("reaumur", TemperatureUnit::Reaumur),
+    ("r\u{00E9}aumur", TemperatureUnit::Reaumur),
+    ("De", TemperatureUnit::Delisle),
+    ("delisle", TemperatureUnit::Delisle),
+    ("N", TemperatureUnit::Newton),
+    ("newton", TemperatureUnit::Newton),
+    ("Ro", TemperatureUnit::Romer),
+    ("R\u{00F8}", TemperatureUnit::Romer),
+    ("romer", TemperatureUnit::Romer),
+    ("r\u{00F8}mer", TemperatureUnit::Romer),
+];
+
+/// Compares two strings, ignoring case.
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
 }
 
-impl fmt::Display for TemperatureParseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            TemperatureParseError::Empty => write!(f, "Empty temperature string"),
-            TemperatureParseError::MissingUnit => write!(f, "Missing temperature unit"),
-            TemperatureParseError::InvalidUnit(unit) => {
-                write!(f, "Invalid temperature unit '{unit}'")
-            }
-            TemperatureParseError::InvalidNumber => write!(f, "Invalid temperature number"),
-            TemperatureParseError::BelowAbsoluteZero => {
-                write!(f, "Temperature less than 0.0k")
-            }
-        }
-    }
-}
+pub use crate::error::{Result, TemperatureError};
 
 /// A temperature value paired with its unit.
+///
+/// The value is an `f64` unless another `Scalar` is chosen, see the
+/// `numeric` module. Comparisons are on the physical temperature, so
+/// `100°C == 212°F`.
+///
+/// The fields are private so that every `Temperature` has been validated.
+/// [`Temperature::new`], `FromStr` and, for constants,
+/// [`Temperature::new_const`] accept finite values not below absolute zero.
+/// [`Temperature::new_with`] takes another policy from the `validate`
+/// module instead; with `NegativeKelvin` the value may be below absolute
+/// zero, so the guarantee only holds for the default policy.
 #[derive(Debug, Clone, Copy)]
-pub struct Temperature {
-    pub value: f64,
-    pub unit: TemperatureUnit,
+pub struct Temperature<T = f64> {
+    value: T,
+    unit: TemperatureUnit,
 }
 
 /// Absolute zero in Kelvin.
-pub const ABSOLUTE_ZERO: Temperature = Temperature {
-    value: 0.0,
-    unit: TemperatureUnit::Kelvin,
-};
+pub const ABSOLUTE_ZERO: Temperature = Temperature::new_const(0.0, TemperatureUnit::Kelvin);
 
-/// Boiling point of water at sea level in Celsius.
-pub const BOILING_POINT: Temperature = Temperature {
-    value: 100.0,
-    unit: TemperatureUnit::Celsius,
-};
+/// Boiling point of water at sea level in Celsius. The `water` module
+/// computes it for other pressures and altitudes.
+pub const BOILING_POINT: Temperature = Temperature::new_const(100.0, TemperatureUnit::Celsius);
 
 /// Freezing point of water in Celsius.
-pub const FREEZING_POINT: Temperature = Temperature {
-    value: 0.0,
-    unit: TemperatureUnit::Celsius,
-};
+pub const FREEZING_POINT: Temperature = Temperature::new_const(0.0, TemperatureUnit::Celsius);
 
-impl fmt::Display for Temperature {
+/// Honours precision, sign, width, fill and alignment; `{:#}` writes the long
+/// form, like `37.5 degrees Celsius`. See the `format` module.
+impl<T: Scalar> fmt::Display for Temperature<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.unit {
-            TemperatureUnit::Kelvin => {
-                write!(f, "{}{}", self.value, self.unit.abbreviation())
-            },
-            _other => {
-                write!(f, "{}\u{00B0}{}", self.value, self.unit.abbreviation())
-            }
+        let value = self.value.to_f64();
+        let precision = f.precision();
+        let sign_plus = f.sign_plus();
+        let long = f.alternate();
+        format::pad(f, |w| {
+            format::write_plus(w, sign_plus, value)?;
+            self.value.fmt_value(w, precision)?;
+            format::write_unit(w, self.unit, long, format::is_singular(value, precision))
+        })
+    }
+}
 
+impl<T: Scalar> Temperature<T> {
+    /// Creates a new temperature, rejecting values below absolute zero and
+    /// values that are NaN or infinite.
+    pub fn new(value: T, unit: TemperatureUnit) -> Result<Temperature<T>> {
+        AbsoluteZero::check(value, unit)?;
+        Ok(Temperature { value, unit })
+    }
+
+    /// Creates a new temperature, rejecting values that `validator` doesn't
+    /// accept.
+    ///
+    /// The validator sees the value as its nearest `f64`. For `f32` values
+    /// this makes `AbsoluteZero` a little stricter than `new`, which accepts
+    /// absolute zero as rounded to `f32`.
+    pub fn new_with(
+        value: T,
+        unit: TemperatureUnit,
+        validator: impl Validator,
+    ) -> Result<Temperature<T>> {
+        validator.validate(value.to_f64(), unit)?;
+        Ok(Temperature { value, unit })
+    }
+
+    /// Parses a temperature like `FromStr`, rejecting values that
+    /// `validator` doesn't accept.
+    pub fn parse_with(input: &str, validator: impl Validator) -> Result<Temperature<T>> {
+        let (value, unit) = parse_parts(input)?;
+        Temperature::new_with(value, unit, validator)
+    }
+
+    /// Creates a temperature without validating it.
+    ///
+    /// The caller must make sure the value is finite and not below absolute
+    /// zero. Nothing unsafe happens otherwise, but conversions and
+    /// comparisons may give meaningless results.
+    pub const fn new_unchecked(value: T, unit: TemperatureUnit) -> Temperature<T> {
+        Temperature { value, unit }
+    }
+
+    /// Returns the reading, in this temperature's unit.
+    pub const fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the unit of the reading.
+    pub const fn unit(&self) -> TemperatureUnit {
+        self.unit
+    }
+
+    /// Converts this temperature to the requested unit.
+    ///
+    /// Every pair of units converts with one affine transform derived from
+    /// the exact scale definitions, so no intermediate unit is involved.
+    ///
+    /// # Panics
+    /// Panics if the result doesn't fit in the value type, such as a
+    /// fixed-point overflow or a float that would become infinite; use
+    /// [`Temperature::try_to`] to handle that case.
+    pub fn to(&self, unit: TemperatureUnit) -> Temperature<T> {
+        match self.try_to(unit) {
+            Ok(temp) => temp,
+            Err(_) => panic!(
+                "{self} does not fit when converted to {}",
+                unit.description()
+            ),
         }
-        
+    }
+
+    /// Converts this temperature to the requested unit, or returns
+    /// `OutOfRange` if the result doesn't fit in the value type.
+    pub fn try_to(&self, unit: TemperatureUnit) -> Result<Temperature<T>> {
+        self.checked_to(unit).ok_or(TemperatureError::OutOfRange {
+            value: self.value.to_f64(),
+            unit: self.unit,
+        })
+    }
+
+    /// Converts this temperature to the requested unit, or returns `None` if
+    /// the result doesn't fit in the value type.
+    pub fn checked_to(&self, unit: TemperatureUnit) -> Option<Temperature<T>> {
+        if self.unit == unit {
+            return Some(*self);
+        }
+
+        Some(Temperature {
+            value: self.value.convert(self.unit, unit)?,
+            unit,
+        })
     }
 }
 
 impl Temperature {
-    /// Creates a new temperature, rejecting values below absolute zero.
-    pub fn new(value: f64, unit: TemperatureUnit) -> Result<Temperature> {
-        let temp = Temperature { value, unit };
-        if temp.to(TemperatureUnit::Kelvin).value < 0.0 {
-            Err(InvalidTemperature)
-        }
-        else {
-            Ok(temp)
+    /// Creates a new temperature in a `const` context, such as a constant
+    /// definition.
+    ///
+    /// # Panics
+    /// Panics if the value is NaN, infinite or below absolute zero. In a
+    /// constant this is a compile error:
+    ///
+    /// ```compile_fail
+    /// use convert_temp::{Temperature, TemperatureUnit};
+    ///
+    /// const TOO_COLD: Temperature = Temperature::new_const(-1.0, TemperatureUnit::Kelvin);
+    /// let _ = TOO_COLD;
+    /// ```
+    pub const fn new_const(value: f64, unit: TemperatureUnit) -> Temperature {
+        assert!(value.is_finite(), "temperature is not finite");
+        let zero = unit.scale().absolute_zero.to_f64();
+        let below = if unit.scale().kelvin_per_degree.numerator < 0 {
+            value > zero
+        } else {
+            value < zero
+        };
+        assert!(!below, "temperature is below absolute zero");
+        Temperature { value, unit }
+    }
+}
+
+/// Comparisons on the physical temperature, for every value type.
+impl<T: Scalar> Temperature<T> {
+    /// Returns true if the two temperatures are equal within either tolerance.
+    ///
+    /// Like `math.isclose` in Python, the temperatures match if their
+    /// difference is at most `absolute`, or at most `relative` times the
+    /// larger of the two in kelvin. Units may differ.
+    pub fn approx_eq(
+        &self,
+        other: &Temperature<T>,
+        absolute: TemperatureDelta,
+        relative: f64,
+    ) -> bool {
+        let a = self.kelvin();
+        let b = other.kelvin();
+        let difference = (a - b).abs();
+        difference <= absolute.to(TemperatureUnit::Kelvin).value().abs()
+            || difference <= relative * a.max(b)
+    }
+
+    /// Returns the colder of the two temperatures, in its own unit.
+    pub fn min(self, other: Temperature<T>) -> Temperature<T> {
+        if other < self {
+            other
+        } else {
+            self
         }
     }
 
-    /// Parses a temperature from a string like `\"37.5C\"`, `\"32F\"`, or `\"273.15K\"`.
-    pub fn from_str(input: &str) -> std::result::Result<Temperature, TemperatureParseError> {
-        let trimmed = input.trim();
-        if trimmed.is_empty() {
-            return Err(TemperatureParseError::Empty);
+    /// Returns the hotter of the two temperatures, in its own unit.
+    pub fn max(self, other: Temperature<T>) -> Temperature<T> {
+        if other > self {
+            other
+        } else {
+            self
         }
+    }
 
-        if trimmed.len() < 2 {
-            return Err(TemperatureParseError::MissingUnit);
+    /// Restricts this temperature to the interval `[min, max]`, which may be
+    /// in any units. The result is in the unit of whichever value is chosen.
+    ///
+    /// # Panics
+    /// Panics if `min` is hotter than `max`.
+    pub fn clamp(self, min: Temperature<T>, max: Temperature<T>) -> Temperature<T> {
+        assert!(min <= max, "clamp: {min} is hotter than {max}");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
         }
+    }
 
-        let (value_part, unit_part) = trimmed.split_at(trimmed.len() - 1);
-        let unit_char = unit_part
-            .chars()
-            .next()
-            .ok_or(TemperatureParseError::MissingUnit)?;
-
-        let unit = match unit_char {
-            'C' => TemperatureUnit::Celsius,
-            'F' => TemperatureUnit::Fahrenheit,
-            'K' => TemperatureUnit::Kelvin,
-            _ => return Err(TemperatureParseError::InvalidUnit(unit_char)),
-        };
+    /// The thermodynamic temperature in kelvin, for comparisons, computed
+    /// from the value's nearest `f64`.
+    ///
+    /// This may be infinite for readings near `f64::MAX`, which still orders
+    /// correctly, so comparisons never panic.
+    fn kelvin(&self) -> f64 {
+        Affine::between(self.unit, TemperatureUnit::Kelvin).apply(self.value.to_f64())
+    }
+}
 
-        let value_str = value_part.trim();
-        if value_str.is_empty() {
-            return Err(TemperatureParseError::InvalidNumber);
-        }
+impl<T: Scalar> PartialEq for Temperature<T> {
+    fn eq(&self, other: &Temperature<T>) -> bool {
+        self.kelvin() == other.kelvin()
+    }
+}
 
-        let value: f64 = value_str
-            .parse()
-            .map_err(|_| TemperatureParseError::InvalidNumber)?;
+impl<T: Scalar> PartialOrd for Temperature<T> {
+    fn partial_cmp(&self, other: &Temperature<T>) -> Option<Ordering> {
+        self.kelvin().partial_cmp(&other.kelvin())
+    }
+}
 
-        Temperature::new(value, unit).map_err(|_| TemperatureParseError::BelowAbsoluteZero)
+/// Parses a temperature from a string like `"37.5C"`, `"37.5 °C"`,
+/// `"98.6 deg F"`, `"300 kelvin"` or `"20℃"`.
+///
+/// Units are matched without regard to case, and an optional degree sign or
+/// `deg`/`degrees` may come before the unit, so `Display` output always
+/// parses back to the same temperature.
+impl<T: Scalar> FromStr for Temperature<T> {
+    type Err = TemperatureError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (value, unit) = parse_parts(input)?;
+        Temperature::new(value, unit)
     }
-    /// Converts this temperature to the requested unit.
-    pub fn to(&self, unit: TemperatureUnit) -> Temperature {
-        match self.unit {
-            TemperatureUnit::Celsius => {
-                match unit {
-                    TemperatureUnit::Celsius =>
-                        Temperature {
-                            value: self.value,
-                            unit: self.unit,
-                        },
-                    TemperatureUnit::Fahrenheit => {
-                        let _temperature = self.value * 9.0 / 5.0 + 32.0;
-                        let _t = Temperature {
-                            value: _temperature,
-                            unit: TemperatureUnit::Fahrenheit,
-                        };
-                        _t
-                    },
-                    TemperatureUnit::Kelvin => {
-                        let _temperature = self.value + 273.15;
-                        let _t = Temperature {
-                            value: _temperature,
-                            unit: TemperatureUnit::Kelvin,
-                        };
-                        _t
-                    }
-                }
-            },
-            TemperatureUnit::Fahrenheit => {
-                match unit {
-                    TemperatureUnit::Celsius => {
-                        let _temperature = (self.value - 32.0 ) * 5.0 /9.0;
-                        let _t = Temperature {
-                            value: _temperature,
-                            unit: TemperatureUnit::Celsius,
-                        };
-                        _t
-                    },
-                    TemperatureUnit::Fahrenheit =>
-                        Temperature {
-                            value: self.value,
-                            unit: self.unit,
-                        },
-                    TemperatureUnit::Kelvin =>
-                        self.to(TemperatureUnit::Celsius).to(TemperatureUnit::Kelvin)
-                }
-            },
-            TemperatureUnit::Kelvin => {
-                match unit {
-                    TemperatureUnit::Celsius => {
-                        let _temperature = self.value - 273.15;
-                        let _t = Temperature {
-                            value: _temperature,
-                            unit: TemperatureUnit::Celsius,
-                        };
-                        _t
-                    },
-                    TemperatureUnit::Fahrenheit =>
-                        self.to(TemperatureUnit::Celsius).to(TemperatureUnit::Fahrenheit),
-                    TemperatureUnit::Kelvin => {
-                        Temperature {
-                            value: self.value,
-                            unit: self.unit,
-                        }
-                    }
-                
-                }
-            }
-        }
+}
+
+/// Spellings of the degree sign accepted before a unit.
+const DEGREE_MARKERS: [&str; 5] = ["\u{00B0}", "\u{00BA}", "degrees", "degree", "deg"];
+
+/// Returns true for characters that may appear in a unit, such as letters
+/// and the degree signs.
+fn is_unit_char(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, '\u{00B0}' | '\u{2103}' | '\u{2109}')
+}
+
+/// Removes an ASCII `suffix` from `input`, ignoring case.
+fn strip_suffix_ignore_case<'a>(input: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = input.len().checked_sub(suffix.len())?;
+    if input.is_char_boundary(split) && input[split..].eq_ignore_ascii_case(suffix) {
+        Some(&input[..split])
+    } else {
+        None
     }
 }
 
-/// Parses a temperature using the standard `FromStr` trait.
-impl FromStr for Temperature {
-    type Err = TemperatureParseError;
+/// Returns the byte range of `part` within `input`, which contains it.
+pub(crate) fn span_of(input: &str, part: &str) -> Range<usize> {
+    let start = part.as_ptr() as usize - input.as_ptr() as usize;
+    start..start + part.len()
+}
+
+/// Parses a number from a slice of `input`, reporting its span on failure.
+pub(crate) fn parse_number(input: &str, part: &str) -> Result<f64> {
+    let number = part.trim();
+    number
+        .parse()
+        .map_err(|source| TemperatureError::InvalidNumber {
+            span: span_of(input, number),
+            source: Some(source),
+        })
+}
+
+/// Parses a string like `"37.5C"` into its value and unit, unvalidated.
+fn parse_parts<T: Scalar>(input: &str) -> Result<(T, TemperatureUnit)> {
+    let (span, unit) = split_unit(input)?;
+    let value = T::parse_value(&input[span.clone()])
+        .map_err(|source| TemperatureError::InvalidNumber { span, source })?;
+    Ok((value, unit))
+}
+
+/// Splits a string like `"37.5C"` into the span of its trimmed number and
+/// its unit.
+pub(crate) fn split_unit(input: &str) -> Result<(Range<usize>, TemperatureUnit)> {
+    split_unit_with(input, &[], &[])
+}
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        Temperature::from_str(s)
+/// Like `split_unit`, also accepting extra degree words and unit names, such
+/// as those of a locale. Both are matched without regard to case.
+pub(crate) fn split_unit_with(
+    input: &str,
+    degree_words: &[&str],
+    unit_names: &[(&str, TemperatureUnit)],
+) -> Result<(Range<usize>, TemperatureUnit)> {
+    let start = input.len() - input.trim_start().len();
+    let trimmed = input.trim();
+    let end = start + trimmed.len();
+    if trimmed.is_empty() {
+        return Err(TemperatureError::Empty);
     }
+
+    if trimmed.len() < 2 {
+        return Err(TemperatureError::MissingUnit { span: end..end });
+    }
+
+    // The unit is the longest known spelling in the trailing run of letters
+    // and symbols, e.g. `C`, `Rø`, `℃` or `kelvin`.
+    let letters_start = trimmed
+        .char_indices()
+        .rev()
+        .take_while(|&(_, c)| is_unit_char(c))
+        .last()
+        .map_or(trimmed.len(), |(index, _)| index);
+    let unit_match = trimmed[letters_start..]
+        .char_indices()
+        .map(|(index, _)| letters_start + index)
+        .find_map(|index| {
+            let name = &trimmed[index..];
+            TemperatureUnit::from_name(name)
+                .or_else(|| {
+                    unit_names
+                        .iter()
+                        .find(|(spelling, _)| eq_ignore_case(spelling, name))
+                        .map(|&(_, unit)| unit)
+                })
+                .map(|unit| (index, unit))
+        });
+
+    let (value_end, unit) = match unit_match {
+        Some((index, unit)) => (index, unit),
+        None => {
+            let last = trimmed.chars().next_back().ok_or(TemperatureError::Empty)?;
+            if last.is_ascii_digit() || last == '.' {
+                return Err(TemperatureError::MissingUnit { span: end..end });
+            }
+            let unit_start = if letters_start < trimmed.len() {
+                start + letters_start
+            } else {
+                end - last.len_utf8()
+            };
+            return Err(TemperatureError::InvalidUnit {
+                unit: last,
+                span: unit_start..end,
+            });
+        }
+    };
+
+    // Drop one degree marker between the number and the unit, as in
+    // `37.5°C`, `37.5 °C` or `98.6 deg F`.
+    let value_part = trimmed[..value_end].trim_end();
+    let value_part = DEGREE_MARKERS
+        .iter()
+        .chain(degree_words)
+        .find_map(|marker| strip_suffix_ignore_case(value_part, marker))
+        .unwrap_or(value_part)
+        .trim_end();
+
+    let value_start = start + (value_part.len() - value_part.trim_start().len());
+    let value_end = start + value_part.len();
+    if value_start >= value_end {
+        return Err(TemperatureError::InvalidNumber {
+            span: start..start,
+            source: None,
+        });
+    }
+
+    Ok((value_start..value_end, unit))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use super::{
-        Temperature,
-        TemperatureParseError,
-        TemperatureUnit,
-        ABSOLUTE_ZERO,
-        BOILING_POINT,
-        FREEZING_POINT,
+        Temperature, TemperatureDelta, TemperatureError, TemperatureUnit, ABSOLUTE_ZERO,
+        BOILING_POINT, FREEZING_POINT,
     };
+    use crate::numeric::Centi;
 
     fn assert_close(actual: f64, expected: f64, epsilon: f64) {
         assert!(
@@ -351,7 +613,7 @@ mod tests {
     fn absolute_zero_fahrenheit_is_valid() {
         let temp_f = Temperature::new(-459.67, TemperatureUnit::Fahrenheit).expect("valid");
         let temp_k = temp_f.to(TemperatureUnit::Kelvin);
-        assert_close(temp_k.value, 0.0, 1e-2);
+        assert_close(temp_k.value, 0.0, 1e-12);
     }
 
     #[test]
@@ -401,20 +663,23 @@ mod tests {
 
     #[test]
     fn parse_temperature_rejects_invalid_unit() {
-        let err = Temperature::from_str("10X").unwrap_err();
-        assert!(matches!(err, TemperatureParseError::InvalidUnit('X')));
+        let err = Temperature::<f64>::from_str("10X").unwrap_err();
+        assert!(matches!(
+            err,
+            TemperatureError::InvalidUnit { unit: 'X', .. }
+        ));
     }
 
     #[test]
     fn parse_temperature_rejects_invalid_number() {
-        let err = Temperature::from_str("abcC").unwrap_err();
-        assert!(matches!(err, TemperatureParseError::InvalidNumber));
+        let err = Temperature::<f64>::from_str("abcC").unwrap_err();
+        assert!(matches!(err, TemperatureError::InvalidNumber { .. }));
     }
 
     #[test]
     fn parse_temperature_rejects_below_absolute_zero() {
-        let err = Temperature::from_str("-300C").unwrap_err();
-        assert!(matches!(err, TemperatureParseError::BelowAbsoluteZero));
+        let err = Temperature::<f64>::from_str("-300C").unwrap_err();
+        assert!(matches!(err, TemperatureError::BelowAbsoluteZero { .. }));
     }
 
     #[test]
@@ -423,4 +688,288 @@ mod tests {
         assert_close(temp.value, 451.0, 1e-12);
         assert!(matches!(temp.unit, TemperatureUnit::Fahrenheit));
     }
+
+    #[test]
+    fn historical_scales_from_boiling_point() {
+        let boiling = Temperature::new(100.0, TemperatureUnit::Celsius).expect("valid");
+        assert_close(boiling.to(TemperatureUnit::Rankine).value, 671.67, 1e-9);
+        assert_close(boiling.to(TemperatureUnit::Reaumur).value, 80.0, 1e-9);
+        assert_close(boiling.to(TemperatureUnit::Delisle).value, 0.0, 1e-9);
+        assert_close(boiling.to(TemperatureUnit::Newton).value, 33.0, 1e-9);
+        assert_close(boiling.to(TemperatureUnit::Romer).value, 60.0, 1e-9);
+    }
+
+    #[test]
+    fn historical_scales_to_celsius() {
+        let cases = [
+            (491.67, TemperatureUnit::Rankine, 0.0),
+            (40.0, TemperatureUnit::Reaumur, 50.0),
+            (150.0, TemperatureUnit::Delisle, 0.0),
+            (16.5, TemperatureUnit::Newton, 50.0),
+            (7.5, TemperatureUnit::Romer, 0.0),
+        ];
+        for (value, unit, celsius) in cases {
+            let temp = Temperature::new(value, unit).expect("valid");
+            assert_close(temp.to(TemperatureUnit::Celsius).value, celsius, 1e-9);
+        }
+    }
+
+    #[test]
+    fn absolute_zero_checked_for_every_scale() {
+        let cases = [
+            (0.0, TemperatureUnit::Rankine),
+            (-218.52, TemperatureUnit::Reaumur),
+            (559.725, TemperatureUnit::Delisle),
+            (-90.1395, TemperatureUnit::Newton),
+            (-135.90375, TemperatureUnit::Romer),
+        ];
+        for (value, unit) in cases {
+            let temp = Temperature::new(value, unit).expect("valid");
+            assert_close(temp.to(TemperatureUnit::Kelvin).value, 0.0, 1e-9);
+        }
+
+        assert!(Temperature::new(-0.001, TemperatureUnit::Rankine).is_err());
+        assert!(Temperature::new(-218.53, TemperatureUnit::Reaumur).is_err());
+        assert!(Temperature::new(559.8, TemperatureUnit::Delisle).is_err());
+        assert!(Temperature::new(-90.2, TemperatureUnit::Newton).is_err());
+        assert!(Temperature::new(-136.0, TemperatureUnit::Romer).is_err());
+    }
+
+    #[test]
+    fn display_formats_historical_scales() {
+        let temp = Temperature::new(80.0, TemperatureUnit::Reaumur).expect("valid");
+        assert_eq!(format!("{temp}"), "80\u{00B0}R\u{00E9}");
+        let temp = Temperature::new(60.0, TemperatureUnit::Romer).expect("valid");
+        assert_eq!(format!("{temp}"), "60\u{00B0}R\u{00F8}");
+        let temp = Temperature::new(671.67, TemperatureUnit::Rankine).expect("valid");
+        assert_eq!(format!("{temp}"), "671.67\u{00B0}Ra");
+    }
+
+    #[test]
+    fn parse_historical_scale_suffixes() {
+        let cases = [
+            ("671.67Ra", TemperatureUnit::Rankine),
+            ("671.67R", TemperatureUnit::Rankine),
+            ("80Re", TemperatureUnit::Reaumur),
+            ("80R\u{00E9}", TemperatureUnit::Reaumur),
+            ("0De", TemperatureUnit::Delisle),
+            ("33N", TemperatureUnit::Newton),
+            ("60Ro", TemperatureUnit::Romer),
+            ("60R\u{00F8}", TemperatureUnit::Romer),
+        ];
+        for (input, unit) in cases {
+            let temp = Temperature::<f64>::from_str(input).expect("valid");
+            assert_eq!(temp.unit, unit, "{input}");
+        }
+    }
+
+    #[test]
+    fn every_pair_converts_boiling_point_consistently() {
+        for from in TemperatureUnit::ALL {
+            let boiling = BOILING_POINT.to(from);
+            for to in TemperatureUnit::ALL {
+                let expected = BOILING_POINT.to(to);
+                assert_close(boiling.to(to).value, expected.value, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn equality_is_physical_across_units() {
+        let boiling_f = Temperature::new(212.0, TemperatureUnit::Fahrenheit).expect("valid");
+        assert_eq!(BOILING_POINT, boiling_f);
+        assert_eq!(FREEZING_POINT, FREEZING_POINT.to(TemperatureUnit::Kelvin));
+        assert_ne!(BOILING_POINT, FREEZING_POINT);
+    }
+
+    #[test]
+    fn ordering_is_physical_across_units() {
+        let mut temps = [
+            Temperature::new(300.0, TemperatureUnit::Kelvin).expect("valid"),
+            Temperature::new(0.0, TemperatureUnit::Fahrenheit).expect("valid"),
+            Temperature::new(0.0, TemperatureUnit::Delisle).expect("valid"),
+            Temperature::new(20.0, TemperatureUnit::Celsius).expect("valid"),
+        ];
+        temps.sort_by(|a, b| a.partial_cmp(b).expect("comparable"));
+        let units: Vec<_> = temps.iter().map(|t| t.unit).collect();
+        assert_eq!(
+            units,
+            [
+                TemperatureUnit::Fahrenheit,
+                TemperatureUnit::Celsius,
+                TemperatureUnit::Kelvin,
+                TemperatureUnit::Delisle,
+            ]
+        );
+        assert!(BOILING_POINT > FREEZING_POINT.to(TemperatureUnit::Fahrenheit));
+    }
+
+    #[test]
+    fn approx_eq_with_absolute_and_relative_tolerance() {
+        let body = Temperature::new(98.6, TemperatureUnit::Fahrenheit).expect("valid");
+        let reading = Temperature::new(37.04, TemperatureUnit::Celsius).expect("valid");
+        let tenth = TemperatureDelta::new(0.1, TemperatureUnit::Kelvin);
+        let hundredth = TemperatureDelta::new(0.01, TemperatureUnit::Kelvin);
+        assert!(body.approx_eq(&reading, tenth, 0.0));
+        assert!(!body.approx_eq(&reading, hundredth, 0.0));
+        assert!(body.approx_eq(&reading, hundredth, 1e-3));
+    }
+
+    #[test]
+    fn min_max_clamp_across_units() {
+        let low = Temperature::new(10.0, TemperatureUnit::Celsius).expect("valid");
+        let high = Temperature::new(86.0, TemperatureUnit::Fahrenheit).expect("valid");
+        assert_eq!(low.min(high).unit, TemperatureUnit::Celsius);
+        assert_eq!(low.max(high).unit, TemperatureUnit::Fahrenheit);
+
+        let hot = Temperature::new(400.0, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(hot.clamp(low, high).unit, TemperatureUnit::Fahrenheit);
+        let cold = Temperature::new(0.0, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(cold.clamp(low, high).unit, TemperatureUnit::Celsius);
+        let mild = Temperature::new(293.15, TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(mild.clamp(low, high).unit, TemperatureUnit::Kelvin);
+    }
+
+    #[test]
+    fn comparisons_work_for_every_value_type() {
+        let body = Temperature::new(37.0f32, TemperatureUnit::Celsius).expect("valid");
+        let fever = Temperature::new(100.4f32, TemperatureUnit::Fahrenheit).expect("valid");
+        assert!(body < fever);
+        assert_eq!(body.max(fever).unit(), TemperatureUnit::Fahrenheit);
+
+        let freezing =
+            Temperature::new(Centi::from_raw(0), TemperatureUnit::Celsius).expect("valid");
+        let also =
+            Temperature::new(Centi::from_raw(27_315), TemperatureUnit::Kelvin).expect("valid");
+        assert_eq!(freezing, also);
+        let warm =
+            Temperature::new(Centi::from_raw(2_000), TemperatureUnit::Celsius).expect("valid");
+        assert_eq!(warm.clamp(freezing, also).unit(), TemperatureUnit::Kelvin);
+        let tolerance = TemperatureDelta::new(0.01, TemperatureUnit::Kelvin);
+        assert!(warm.approx_eq(
+            &freezing,
+            TemperatureDelta::new(20.0, TemperatureUnit::Celsius),
+            0.0
+        ));
+        assert!(!warm.approx_eq(&freezing, tolerance, 0.0));
+    }
+
+    #[test]
+    fn rejects_non_finite_values() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let err = Temperature::new(value, TemperatureUnit::Celsius).unwrap_err();
+            assert!(matches!(err, TemperatureError::NonFinite { .. }), "{value}");
+        }
+        assert!(Temperature::new(f32::NAN, TemperatureUnit::Kelvin).is_err());
+
+        for input in ["NaNC", "inf K", "-infinityF"] {
+            let err = Temperature::<f64>::from_str(input).unwrap_err();
+            assert!(matches!(err, TemperatureError::NonFinite { .. }), "{input}");
+        }
+    }
+
+    #[test]
+    fn conversion_overflow_is_reported() {
+        let huge = Temperature::new(f64::MAX, TemperatureUnit::Celsius).expect("valid");
+        let err = huge.try_to(TemperatureUnit::Fahrenheit).unwrap_err();
+        assert!(matches!(err, TemperatureError::OutOfRange { .. }));
+        assert!(huge.checked_to(TemperatureUnit::Fahrenheit).is_none());
+
+        // The result fits even though the intermediate product overflows.
+        let large = Temperature::new(1e307, TemperatureUnit::Celsius).expect("valid");
+        assert_close(large.to(TemperatureUnit::Kelvin).value, 1e307, 1e292);
+        assert!(huge > large.to(TemperatureUnit::Newton));
+    }
+
+    #[test]
+    fn const_constructor_validates() {
+        const BODY: Temperature = Temperature::new_const(98.6, TemperatureUnit::Fahrenheit);
+        assert_eq!(BODY.value(), 98.6);
+        assert_eq!(BODY.unit(), TemperatureUnit::Fahrenheit);
+        assert_eq!(ABSOLUTE_ZERO.value(), 0.0);
+        assert_eq!(
+            Temperature::new_const(559.725, TemperatureUnit::Delisle),
+            ABSOLUTE_ZERO
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "below absolute zero")]
+    fn const_constructor_panics_at_runtime() {
+        let _ = Temperature::new_const(560.0, TemperatureUnit::Delisle);
+    }
+
+    #[test]
+    fn new_unchecked_skips_validation() {
+        let temp = Temperature::new_unchecked(-1.0, TemperatureUnit::Kelvin);
+        assert_eq!(temp.value(), -1.0);
+        assert!(Temperature::new(temp.value(), temp.unit()).is_err());
+    }
+
+    #[test]
+    fn parses_real_world_spellings() {
+        let cases = [
+            ("37.5\u{00B0}C", 37.5, TemperatureUnit::Celsius),
+            ("37.5 \u{00B0}C", 37.5, TemperatureUnit::Celsius),
+            ("37.5 c", 37.5, TemperatureUnit::Celsius),
+            ("98.6 deg F", 98.6, TemperatureUnit::Fahrenheit),
+            ("98.6degf", 98.6, TemperatureUnit::Fahrenheit),
+            ("-40 degrees Fahrenheit", -40.0, TemperatureUnit::Fahrenheit),
+            ("300 kelvin", 300.0, TemperatureUnit::Kelvin),
+            ("300 Kelvins", 300.0, TemperatureUnit::Kelvin),
+            ("20\u{2103}", 20.0, TemperatureUnit::Celsius),
+            ("68 \u{2109}", 68.0, TemperatureUnit::Fahrenheit),
+            ("300\u{212A}", 300.0, TemperatureUnit::Kelvin),
+            ("80 R\u{00E9}aumur", 80.0, TemperatureUnit::Reaumur),
+            ("60 romer", 60.0, TemperatureUnit::Romer),
+            ("33 newton", 33.0, TemperatureUnit::Newton),
+            ("0 de", 0.0, TemperatureUnit::Delisle),
+        ];
+        for (input, value, unit) in cases {
+            let temp = Temperature::<f64>::from_str(input).expect(input);
+            assert_eq!((temp.value(), temp.unit()), (value, unit), "{input}");
+        }
+
+        assert!(Temperature::<f64>::from_str("37.5\u{00B0}").is_err());
+        assert!(Temperature::<f64>::from_str("37.5 deg").is_err());
+        assert!(Temperature::<f64>::from_str("37.5 \u{00B0}\u{00B0}C").is_err());
+    }
+
+    #[test]
+    fn display_output_round_trips() {
+        for unit in TemperatureUnit::ALL {
+            for kelvin in [0.0, 0.1, 255.372, 310.15, 1e-7, 1e21] {
+                let temp = Temperature::new(kelvin, TemperatureUnit::Kelvin)
+                    .expect("valid")
+                    .to(unit);
+                let parsed = Temperature::<f64>::from_str(&format!("{temp}")).expect("parses");
+                assert_eq!(
+                    (parsed.value(), parsed.unit()),
+                    (temp.value(), temp.unit()),
+                    "{temp}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unit_lookup_by_name() {
+        assert_eq!(
+            TemperatureUnit::from_name("KELVIN"),
+            Some(TemperatureUnit::Kelvin)
+        );
+        assert_eq!(
+            TemperatureUnit::from_name("\u{212A}"),
+            Some(TemperatureUnit::Kelvin)
+        );
+        assert_eq!(
+            TemperatureUnit::from_name("r\u{00F8}"),
+            Some(TemperatureUnit::Romer)
+        );
+        assert_eq!(
+            TemperatureUnit::from_name("r"),
+            Some(TemperatureUnit::Rankine)
+        );
+        assert_eq!(TemperatureUnit::from_name("deg"), None);
+    }
 }
@@ -0,0 +1,697 @@
+//! Thermocouple voltages.
+//!
+//! A thermocouple produces an EMF that depends on the temperature difference
+//! between its measuring junction and its reference, or cold, junction.
+//! These functions use the NIST ITS-90 reference functions (NIST Monograph
+//! 175) for the letter-designated types B, E, J, K, N, R, S and T, which
+//! give the EMF in millivolts for a measuring junction at `t` °C and a
+//! reference junction at 0 °C.
+//!
+//! Going from EMF to temperature, [`Thermocouple::temperature`] solves the
+//! reference function with Newton's method, so both directions agree to
+//! within a microkelvin. Readings with the cold junction at another
+//! temperature, as on a data-acquisition terminal block, are compensated by
+//! the `_relative_to` variants.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::thermocouple::Thermocouple;
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! let oven = Temperature::new(100.0, TemperatureUnit::Celsius).unwrap();
+//! assert_eq!(format!("{:.3}", Thermocouple::K.emf(oven).unwrap()), "4.096");
+//!
+//! let terminals = Temperature::new(25.0, TemperatureUnit::Celsius).unwrap();
+//! let reading = Thermocouple::K.temperature_relative_to(3.096, terminals).unwrap();
+//! assert_eq!(format!("{reading:.1}"), "100.0\u{00B0}C");
+//! ```
+
+use crate::temperature::{Result, Temperature, TemperatureError, TemperatureUnit};
+
+/// A letter-designated thermocouple type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Thermocouple {
+    /// Platinum-30% rhodium / platinum-6% rhodium, 0 to 1820 °C.
+    B,
+    /// Nickel-chromium / constantan, -270 to 1000 °C.
+    E,
+    /// Iron / constantan, -210 to 1200 °C.
+    J,
+    /// Nickel-chromium / nickel-aluminium, -270 to 1372 °C.
+    K,
+    /// Nicrosil / nisil, -270 to 1300 °C.
+    N,
+    /// Platinum-13% rhodium / platinum, -50 to 1768.1 °C.
+    R,
+    /// Platinum-10% rhodium / platinum, -50 to 1768.1 °C.
+    S,
+    /// Copper / constantan, -270 to 400 °C.
+    T,
+}
+
+/// One piece of a reference function, valid up to `max` °C.
+struct Segment {
+    max: f64,
+    /// Polynomial coefficients `c0`, `c1`, ... in mV/°Cⁿ.
+    coefficients: &'static [f64],
+    /// The extra term `a0 · exp(a1 · (t - a2)²)` of type K above 0 °C.
+    exponential: Option<(f64, f64, f64)>,
+}
+
+/// The reference function of a thermocouple type.
+struct Reference {
+    /// Lowest temperature of the first segment, in °C.
+    min: f64,
+    segments: &'static [Segment],
+    /// The range, in °C, over which EMF is converted back to temperature.
+    /// It is narrower than the full range where the function flattens out
+    /// near -270 °C, and for type B, whose EMF is not monotonic below about
+    /// 250 °C.
+    inverse: (f64, f64),
+}
+
+const fn segment(max: f64, coefficients: &'static [f64]) -> Segment {
+    Segment {
+        max,
+        coefficients,
+        exponential: None,
+    }
+}
+
+const B: Reference = Reference {
+    min: 0.0,
+    segments: &[
+        segment(
+            630.615,
+            &[
+                0.0,
+                -0.246_508_183_460e-3,
+                0.590_404_211_710e-5,
+                -0.132_579_316_360e-8,
+                0.156_682_919_010e-11,
+                -0.169_445_292_400e-14,
+                0.629_903_470_940e-18,
+            ],
+        ),
+        segment(
+            1820.0,
+            &[
+                -0.389_381_686_210e1,
+                0.285_717_474_700e-1,
+                -0.848_851_047_850e-4,
+                0.157_852_801_640e-6,
+                -0.168_353_448_640e-9,
+                0.111_097_940_130e-12,
+                -0.445_154_310_330e-16,
+                0.989_756_408_210e-20,
+                -0.937_913_302_890e-24,
+            ],
+        ),
+    ],
+    inverse: (250.0, 1820.0),
+};
+
+const E: Reference = Reference {
+    min: -270.0,
+    segments: &[
+        segment(
+            0.0,
+            &[
+                0.0,
+                0.586_655_087_080e-1,
+                0.454_109_771_240e-4,
+                -0.779_980_486_860e-6,
+                -0.258_001_608_430e-7,
+                -0.594_525_830_570e-9,
+                -0.932_140_586_670e-11,
+                -0.102_876_055_340e-12,
+                -0.803_701_236_210e-15,
+                -0.439_794_973_910e-17,
+                -0.164_147_763_550e-19,
+                -0.396_736_195_160e-22,
+                -0.558_273_287_210e-25,
+                -0.346_578_420_130e-28,
+            ],
+        ),
+        segment(
+            1000.0,
+            &[
+                0.0,
+                0.586_655_087_100e-1,
+                0.450_322_755_820e-4,
+                0.289_084_072_120e-7,
+                -0.330_568_966_520e-9,
+                0.650_244_032_700e-12,
+                -0.191_974_955_040e-15,
+                -0.125_366_004_970e-17,
+                0.214_892_175_690e-20,
+                -0.143_880_417_820e-23,
+                0.359_608_994_810e-27,
+            ],
+        ),
+    ],
+    inverse: (-200.0, 1000.0),
+};
+
+const J: Reference = Reference {
+    min: -210.0,
+    segments: &[
+        segment(
+            760.0,
+            &[
+                0.0,
+                0.503_811_878_150e-1,
+                0.304_758_369_300e-4,
+                -0.856_810_657_200e-7,
+                0.132_281_952_950e-9,
+                -0.170_529_583_370e-12,
+                0.209_480_906_970e-15,
+                -0.125_383_953_360e-18,
+                0.156_317_256_970e-22,
+            ],
+        ),
+        segment(
+            1200.0,
+            &[
+                0.296_456_256_810e3,
+                -0.149_761_277_860e1,
+                0.317_871_039_240e-2,
+                -0.318_476_867_010e-5,
+                0.157_208_190_040e-8,
+                -0.306_913_690_560e-12,
+            ],
+        ),
+    ],
+    inverse: (-210.0, 1200.0),
+};
+
+const K: Reference = Reference {
+    min: -270.0,
+    segments: &[
+        segment(
+            0.0,
+            &[
+                0.0,
+                0.394_501_280_250e-1,
+                0.236_223_735_980e-4,
+                -0.328_589_067_840e-6,
+                -0.499_048_287_770e-8,
+                -0.675_090_591_730e-10,
+                -0.574_103_274_280e-12,
+                -0.310_888_728_940e-14,
+                -0.104_516_093_650e-16,
+                -0.198_892_668_780e-19,
+                -0.163_226_974_860e-22,
+            ],
+        ),
+        Segment {
+            max: 1372.0,
+            coefficients: &[
+                -0.176_004_136_860e-1,
+                0.389_212_049_750e-1,
+                0.185_587_700_320e-4,
+                -0.994_575_928_740e-7,
+                0.318_409_457_190e-9,
+                -0.560_728_448_890e-12,
+                0.560_750_590_590e-15,
+                -0.320_207_200_030e-18,
+                0.971_511_471_520e-22,
+                -0.121_047_212_750e-25,
+            ],
+            exponential: Some((
+                0.118_597_600_000,
+                -0.118_343_200_000e-3,
+                0.126_968_600_000e3,
+            )),
+        },
+    ],
+    inverse: (-200.0, 1372.0),
+};
+
+const N: Reference = Reference {
+    min: -270.0,
+    segments: &[
+        segment(
+            0.0,
+            &[
+                0.0,
+                0.261_591_059_620e-1,
+                0.109_574_842_280e-4,
+                -0.938_411_115_540e-7,
+                -0.464_120_397_590e-10,
+                -0.263_033_577_160e-11,
+                -0.226_534_380_030e-13,
+                -0.760_893_007_910e-16,
+                -0.934_196_678_350e-19,
+            ],
+        ),
+        segment(
+            1300.0,
+            &[
+                0.0,
+                0.259_293_946_010e-1,
+                0.157_101_418_800e-4,
+                0.438_256_272_370e-7,
+                -0.252_611_697_940e-9,
+                0.643_118_193_390e-12,
+                -0.100_634_715_190e-14,
+                0.997_453_389_920e-18,
+                -0.608_632_456_070e-21,
+                0.208_492_293_390e-24,
+                -0.306_821_961_510e-28,
+            ],
+        ),
+    ],
+    inverse: (-200.0, 1300.0),
+};
+
+const R: Reference = Reference {
+    min: -50.0,
+    segments: &[
+        segment(
+            1064.18,
+            &[
+                0.0,
+                0.528_961_729_765e-2,
+                0.139_166_589_782e-4,
+                -0.238_855_693_017e-7,
+                0.356_916_001_063e-10,
+                -0.462_347_666_298e-13,
+                0.500_777_441_034e-16,
+                -0.373_105_886_191e-19,
+                0.157_716_482_367e-22,
+                -0.281_038_625_251e-26,
+            ],
+        ),
+        segment(
+            1664.5,
+            &[
+                0.295_157_925_316e1,
+                -0.252_061_251_332e-2,
+                0.159_564_501_865e-4,
+                -0.764_085_947_576e-8,
+                0.205_305_291_024e-11,
+                -0.293_359_668_173e-15,
+            ],
+        ),
+        segment(
+            1768.1,
+            &[
+                0.152_232_118_209e3,
+                -0.268_819_888_545,
+                0.171_280_280_471e-3,
+                -0.345_895_706_453e-7,
+                -0.934_633_971_046e-14,
+            ],
+        ),
+    ],
+    inverse: (-50.0, 1768.1),
+};
+
+const S: Reference = Reference {
+    min: -50.0,
+    segments: &[
+        segment(
+            1064.18,
+            &[
+                0.0,
+                0.540_313_308_631e-2,
+                0.125_934_289_740e-4,
+                -0.232_477_968_689e-7,
+                0.322_028_823_036e-10,
+                -0.331_465_196_389e-13,
+                0.255_744_251_786e-16,
+                -0.125_068_871_393e-19,
+                0.271_443_176_145e-23,
+            ],
+        ),
+        segment(
+            1664.5,
+            &[
+                0.132_900_444_085e1,
+                0.334_509_311_344e-2,
+                0.654_805_192_818e-5,
+                -0.164_856_259_209e-8,
+                0.129_989_605_174e-13,
+            ],
+        ),
+        segment(
+            1768.1,
+            &[
+                0.146_628_232_636e3,
+                -0.258_430_516_752,
+                0.163_693_574_641e-3,
+                -0.330_439_046_987e-7,
+                -0.943_223_690_612e-14,
+            ],
+        ),
+    ],
+    inverse: (-50.0, 1768.1),
+};
+
+const T: Reference = Reference {
+    min: -270.0,
+    segments: &[
+        segment(
+            0.0,
+            &[
+                0.0,
+                0.387_481_063_640e-1,
+                0.441_944_343_470e-4,
+                0.118_443_231_050e-6,
+                0.200_329_735_540e-7,
+                0.901_380_195_590e-9,
+                0.226_511_565_930e-10,
+                0.360_711_542_050e-12,
+                0.384_939_398_830e-14,
+                0.282_135_219_250e-16,
+                0.142_515_947_790e-18,
+                0.487_686_622_860e-21,
+                0.107_955_392_700e-23,
+                0.139_450_270_620e-26,
+                0.797_951_539_270e-30,
+            ],
+        ),
+        segment(
+            400.0,
+            &[
+                0.0,
+                0.387_481_063_640e-1,
+                0.332_922_278_800e-4,
+                0.206_182_434_040e-6,
+                -0.218_822_568_460e-8,
+                0.109_968_809_280e-10,
+                -0.308_157_587_720e-13,
+                0.454_791_352_900e-16,
+                -0.275_129_016_730e-19,
+            ],
+        ),
+    ],
+    inverse: (-200.0, 400.0),
+};
+
+impl Segment {
+    /// Returns the EMF in mV, and its slope in mV/°C, at `t` °C.
+    fn evaluate(&self, t: f64) -> (f64, f64) {
+        // Horner's rule for the polynomial and its derivative together.
+        let (mut emf, mut slope) = (0.0, 0.0);
+        for &c in self.coefficients.iter().rev() {
+            slope = slope * t + emf;
+            emf = emf * t + c;
+        }
+        if let Some((a0, a1, a2)) = self.exponential {
+            let term = a0 * (a1 * (t - a2) * (t - a2)).exp();
+            emf += term;
+            slope += term * 2.0 * a1 * (t - a2);
+        }
+        (emf, slope)
+    }
+}
+
+impl Reference {
+    fn max(&self) -> f64 {
+        self.segments[self.segments.len() - 1].max
+    }
+
+    /// Returns the EMF and its slope at `t` °C, which must be in range.
+    fn evaluate(&self, t: f64) -> (f64, f64) {
+        self.segments
+            .iter()
+            .find(|segment| t <= segment.max)
+            .unwrap_or(&self.segments[self.segments.len() - 1])
+            .evaluate(t)
+    }
+
+    /// Solves `emf(t) = target` for `t` in the inverse range, which must
+    /// contain a solution.
+    fn solve(&self, target: f64) -> f64 {
+        let (mut low, mut high) = self.inverse;
+        let (low_emf, _) = self.evaluate(low);
+        let (high_emf, _) = self.evaluate(high);
+        let mut t = low + (high - low) * (target - low_emf) / (high_emf - low_emf);
+
+        // Newton's method, falling back to bisection whenever a step would
+        // leave the bracket. The EMF rises with temperature over the whole
+        // inverse range, so the bracket always holds the root.
+        for _ in 0..100 {
+            let (emf, slope) = self.evaluate(t);
+            let error = emf - target;
+            if error.abs() < 1e-12 {
+                break;
+            }
+            if error < 0.0 {
+                low = t;
+            } else {
+                high = t;
+            }
+            let next = t - error / slope;
+            t = if next > low && next < high {
+                next
+            } else {
+                (low + high) / 2.0
+            };
+        }
+        t
+    }
+}
+
+impl Thermocouple {
+    /// Every type, in alphabetical order.
+    pub const ALL: [Thermocouple; 8] = [
+        Thermocouple::B,
+        Thermocouple::E,
+        Thermocouple::J,
+        Thermocouple::K,
+        Thermocouple::N,
+        Thermocouple::R,
+        Thermocouple::S,
+        Thermocouple::T,
+    ];
+
+    fn reference(self) -> &'static Reference {
+        match self {
+            Thermocouple::B => &B,
+            Thermocouple::E => &E,
+            Thermocouple::J => &J,
+            Thermocouple::K => &K,
+            Thermocouple::N => &N,
+            Thermocouple::R => &R,
+            Thermocouple::S => &S,
+            Thermocouple::T => &T,
+        }
+    }
+
+    /// Returns the temperature range of the reference function.
+    pub fn range(self) -> (Temperature, Temperature) {
+        let reference = self.reference();
+        (celsius(reference.min), celsius(reference.max()))
+    }
+
+    /// Returns the EMF from `from` °C to `to` °C, both in the inverse range.
+    fn emf_between(self, from: f64, to: f64) -> f64 {
+        let reference = self.reference();
+        reference.evaluate(to).0 - reference.evaluate(from).0
+    }
+
+    /// Converts `temp` to Celsius, checking that it is in range.
+    fn check(self, temp: Temperature) -> Result<f64> {
+        let (min, max) = self.range();
+        let t = temp.to(TemperatureUnit::Celsius).value();
+        if t < min.value() || t > max.value() {
+            return Err(TemperatureError::OutOfBounds {
+                value: temp.value(),
+                unit: temp.unit(),
+                min,
+                max,
+            });
+        }
+        Ok(t)
+    }
+
+    /// Returns the EMF, in millivolts, with the measuring junction at
+    /// `temp` and the reference junction at 0 °C.
+    ///
+    /// Fails with `OutOfBounds` if `temp` is outside the type's range.
+    pub fn emf(self, temp: Temperature) -> Result<f64> {
+        self.emf_relative_to(temp, celsius(0.0))
+    }
+
+    /// Returns the EMF, in millivolts, with the measuring junction at
+    /// `temp` and the reference junction at `reference`.
+    ///
+    /// Fails with `OutOfBounds` if either temperature is outside the type's
+    /// range.
+    pub fn emf_relative_to(self, temp: Temperature, reference: Temperature) -> Result<f64> {
+        let to = self.check(temp)?;
+        let from = self.check(reference)?;
+        Ok(self.emf_between(from, to))
+    }
+
+    /// Returns the temperature, in Celsius, of a measuring junction
+    /// producing `emf` millivolts with the reference junction at 0 °C.
+    ///
+    /// Fails with `OutOfDomain` if `emf` is outside the range NIST gives for
+    /// converting back, such as -5.891 to 54.886 mV for type K.
+    pub fn temperature(self, emf: f64) -> Result<Temperature> {
+        self.temperature_relative_to(emf, celsius(0.0))
+    }
+
+    /// Returns the temperature, in Celsius, of a measuring junction
+    /// producing `emf` millivolts with the reference junction at
+    /// `reference`.
+    ///
+    /// The reference junction's own EMF is added before converting, which
+    /// is cold-junction compensation. Fails with `OutOfBounds` if
+    /// `reference` is outside the type's range, and with `OutOfDomain` if the
+    /// compensated EMF is outside the range for converting back.
+    pub fn temperature_relative_to(self, emf: f64, reference: Temperature) -> Result<Temperature> {
+        let function = self.reference();
+        let (low, high) = function.inverse;
+        let cold = self.check(reference)?;
+        let min = self.emf_between(cold, low);
+        let max = self.emf_between(cold, high);
+        if !(min..=max).contains(&emf) {
+            return Err(TemperatureError::OutOfDomain {
+                quantity: "EMF",
+                value: emf,
+                unit: "mV",
+                min,
+                max,
+            });
+        }
+
+        let compensated = emf + function.evaluate(cold).0;
+        Temperature::new(function.solve(compensated), TemperatureUnit::Celsius)
+    }
+}
+
+fn celsius(value: f64) -> Temperature {
+    Temperature::new_const(value, TemperatureUnit::Celsius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Thermocouple;
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+    use crate::validate::NegativeKelvin;
+
+    fn celsius(value: f64) -> Temperature {
+        Temperature::new(value, TemperatureUnit::Celsius).expect("valid")
+    }
+
+    #[test]
+    fn matches_nist_tables() {
+        // NIST Monograph 175, rounded to the microvolt.
+        let table = [
+            (Thermocouple::B, 100.0, 0.033),
+            (Thermocouple::B, 1000.0, 4.834),
+            (Thermocouple::B, 1500.0, 10.099),
+            (Thermocouple::B, 1820.0, 13.820),
+            (Thermocouple::E, -200.0, -8.825),
+            (Thermocouple::E, 100.0, 6.319),
+            (Thermocouple::E, 500.0, 37.005),
+            (Thermocouple::J, -200.0, -7.890),
+            (Thermocouple::J, 100.0, 5.269),
+            (Thermocouple::J, 1000.0, 57.953),
+            (Thermocouple::J, 1100.0, 63.792),
+            (Thermocouple::K, -200.0, -5.891),
+            (Thermocouple::K, 100.0, 4.096),
+            (Thermocouple::K, 1000.0, 41.276),
+            (Thermocouple::K, 1372.0, 54.886),
+            (Thermocouple::N, -200.0, -3.990),
+            (Thermocouple::N, 100.0, 2.774),
+            (Thermocouple::N, 1000.0, 36.256),
+            (Thermocouple::R, 100.0, 0.647),
+            (Thermocouple::R, 1000.0, 10.506),
+            (Thermocouple::R, 1600.0, 18.849),
+            (Thermocouple::R, 1700.0, 20.222),
+            (Thermocouple::S, 100.0, 0.646),
+            (Thermocouple::S, 1000.0, 9.587),
+            (Thermocouple::S, 1600.0, 16.777),
+            (Thermocouple::S, 1700.0, 17.947),
+            (Thermocouple::T, -200.0, -5.603),
+            (Thermocouple::T, 100.0, 4.279),
+            (Thermocouple::T, 300.0, 14.862),
+        ];
+        for (thermocouple, t, millivolts) in table {
+            let emf = thermocouple.emf(celsius(t)).unwrap();
+            assert!(
+                (emf - millivolts).abs() < 0.0005,
+                "{thermocouple:?} at {t}: {emf}"
+            );
+        }
+    }
+
+    #[test]
+    fn segments_join_up() {
+        for thermocouple in Thermocouple::ALL {
+            let reference = thermocouple.reference();
+            for pair in reference.segments.windows(2) {
+                let t = pair[0].max;
+                let (below, _) = pair[0].evaluate(t);
+                let (above, _) = pair[1].evaluate(t);
+                assert!(
+                    (below - above).abs() < 1e-6,
+                    "{thermocouple:?} at {t}: {below} {above}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        for thermocouple in Thermocouple::ALL {
+            let (low, high) = thermocouple.reference().inverse;
+            for step in 0..=20 {
+                let t = low + (high - low) * step as f64 / 20.0;
+                let emf = thermocouple.emf(celsius(t)).unwrap();
+                let back = thermocouple.temperature(emf).unwrap();
+                assert!(
+                    (back.value() - t).abs() < 1e-6,
+                    "{thermocouple:?} at {t}: {back}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compensates_for_the_cold_junction() {
+        let terminals = Temperature::new(77.0, TemperatureUnit::Fahrenheit).unwrap();
+        let oven = celsius(250.0);
+        let emf = Thermocouple::J.emf_relative_to(oven, terminals).unwrap();
+        let direct = Thermocouple::J.emf(oven).unwrap() - Thermocouple::J.emf(terminals).unwrap();
+        assert!((emf - direct).abs() < 1e-12);
+
+        let reading = Thermocouple::J
+            .temperature_relative_to(emf, terminals)
+            .unwrap();
+        assert_eq!(reading.unit(), TemperatureUnit::Celsius);
+        assert!((reading.value() - 250.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reports_values_out_of_range() {
+        let err = Thermocouple::T.emf(celsius(500.0)).unwrap_err();
+        assert!(matches!(err, TemperatureError::OutOfBounds { .. }));
+        assert_eq!(
+            format!("{err}"),
+            "500\u{00B0}C is outside -270\u{00B0}C to 400\u{00B0}C"
+        );
+
+        let err = Thermocouple::K.temperature(60.0).unwrap_err();
+        assert!(matches!(
+            err,
+            TemperatureError::OutOfDomain {
+                quantity: "EMF",
+                ..
+            }
+        ));
+        assert!(Thermocouple::B.temperature(0.1).is_err());
+        assert!(Thermocouple::K
+            .temperature_relative_to(1.0, celsius(2000.0))
+            .is_err());
+
+        let spin = Temperature::new_with(-5.0, TemperatureUnit::Kelvin, NegativeKelvin).unwrap();
+        assert!(Thermocouple::T.emf(spin).is_err());
+    }
+}
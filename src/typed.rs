@@ -0,0 +1,287 @@
+//! Temperatures whose unit is checked at compile time.
+//!
+//! A `TypedTemperature<Celsius>` can only hold a Celsius reading, so a
+//! function that takes one can't be handed Fahrenheit by mistake.
+//! Conversions between scales use `From`/`Into`, with the affine
+//! coefficients computed at compile time, and the value is stored as a bare
+//! `f64`. `From` also bridges to and from the dynamic `Temperature`.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::typed::{Celsius, Fahrenheit, TypedTemperature};
+//! use convert_temp::{Temperature, TemperatureUnit};
+//!
+//! fn oven_setting(temp: TypedTemperature<Celsius>) -> f64 {
+//!     temp.value()
+//! }
+//!
+//! let recipe = TypedTemperature::<Fahrenheit>::new(356.0).unwrap();
+//! assert_eq!(oven_setting(recipe.into()), 180.0);
+//!
+//! let reading = Temperature::new(300.0, TemperatureUnit::Kelvin).unwrap();
+//! let celsius: TypedTemperature<Celsius> = reading.into();
+//! let dynamic: Temperature = celsius.into();
+//! assert_eq!(dynamic.unit(), TemperatureUnit::Celsius);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use crate::scale::Affine;
+use crate::temperature::{Result, Temperature, TemperatureError, TemperatureUnit};
+
+/// A temperature scale known at compile time.
+pub trait StaticUnit: Copy + fmt::Debug {
+    /// The matching runtime unit.
+    const UNIT: TemperatureUnit;
+}
+
+macro_rules! static_units {
+    ($($(#[$doc:meta])* $marker:ident,)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct $marker;
+
+            impl StaticUnit for $marker {
+                const UNIT: TemperatureUnit = TemperatureUnit::$marker;
+            }
+        )*
+    };
+}
+
+static_units! {
+    /// The Celsius scale.
+    Celsius,
+    /// The Fahrenheit scale.
+    Fahrenheit,
+    /// The kelvin scale.
+    Kelvin,
+    /// The Rankine scale.
+    Rankine,
+    /// The Réaumur scale.
+    Reaumur,
+    /// The Delisle scale.
+    Delisle,
+    /// The Newton scale.
+    Newton,
+    /// The Rømer scale.
+    Romer,
+}
+
+/// A temperature on the scale `S`, stored as an `f64`.
+#[derive(Debug, Clone, Copy)]
+pub struct TypedTemperature<S: StaticUnit> {
+    value: f64,
+    unit: PhantomData<S>,
+}
+
+impl<S: StaticUnit> TypedTemperature<S> {
+    /// Creates a new temperature on scale `S`, rejecting values below
+    /// absolute zero.
+    pub fn new(value: f64) -> Result<TypedTemperature<S>> {
+        Temperature::new(value, S::UNIT).map(|temp| TypedTemperature::from_value(temp.value()))
+    }
+
+    /// Returns the reading on scale `S`.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the runtime unit, `S::UNIT`.
+    pub fn unit(&self) -> TemperatureUnit {
+        S::UNIT
+    }
+
+    /// Converts to another scale. Equivalent to `into()`, but usable when the
+    /// target can't be inferred.
+    ///
+    /// # Panics
+    /// Panics if the result would be infinite, as for `f64::MAX` Newton in
+    /// kelvin; use [`TypedTemperature::try_convert`] to handle that case.
+    pub fn convert<U: StaticUnit>(self) -> TypedTemperature<U> {
+        match self.try_convert() {
+            Ok(temp) => temp,
+            Err(_) => panic!(
+                "{self} does not fit when converted to {}",
+                U::UNIT.scale().name
+            ),
+        }
+    }
+
+    /// Converts to another scale, or returns `OutOfRange` if the result would
+    /// be infinite.
+    pub fn try_convert<U: StaticUnit>(self) -> Result<TypedTemperature<U>> {
+        let value = const { Affine::between(S::UNIT, U::UNIT) }.apply(self.value);
+        if value.is_finite() {
+            Ok(TypedTemperature::from_value(value))
+        } else {
+            Err(TemperatureError::OutOfRange {
+                value: self.value,
+                unit: S::UNIT,
+            })
+        }
+    }
+
+    const fn from_value(value: f64) -> TypedTemperature<S> {
+        TypedTemperature {
+            value,
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<S: StaticUnit> PartialEq for TypedTemperature<S> {
+    fn eq(&self, other: &TypedTemperature<S>) -> bool {
+        self.value == other.value
+    }
+}
+
+/// Orders by physical temperature, so Delisle readings compare in reverse.
+impl<S: StaticUnit> PartialOrd for TypedTemperature<S> {
+    fn partial_cmp(&self, other: &TypedTemperature<S>) -> Option<Ordering> {
+        let ordering = self.value.partial_cmp(&other.value)?;
+        if S::UNIT.scale().kelvin_per_degree.numerator < 0 {
+            Some(ordering.reverse())
+        } else {
+            Some(ordering)
+        }
+    }
+}
+
+impl<S: StaticUnit> fmt::Display for TypedTemperature<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Temperature::from(*self), f)
+    }
+}
+
+/// Parses any supported unit, like `Temperature`, and converts to scale `S`.
+impl<S: StaticUnit> FromStr for TypedTemperature<S> {
+    type Err = TemperatureError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        input.parse::<Temperature>().map(TypedTemperature::from)
+    }
+}
+
+impl<S: StaticUnit> From<TypedTemperature<S>> for Temperature {
+    fn from(temp: TypedTemperature<S>) -> Temperature {
+        Temperature::new_unchecked(temp.value, S::UNIT)
+    }
+}
+
+/// Converts a dynamic temperature to scale `S`.
+///
+/// Panics if the result would be infinite, like [`Temperature::to`]; call
+/// [`Temperature::try_to`] first to handle that case.
+impl<S: StaticUnit> From<Temperature> for TypedTemperature<S> {
+    fn from(temp: Temperature) -> TypedTemperature<S> {
+        TypedTemperature::from_value(temp.to(S::UNIT).value())
+    }
+}
+
+macro_rules! typed_conversions {
+    ($from:ident => $($to:ident),*) => {
+        $(
+            /// Converts between scales, panicking like
+            /// [`TypedTemperature::convert`] if the result would be infinite.
+            impl From<TypedTemperature<$from>> for TypedTemperature<$to> {
+                fn from(temp: TypedTemperature<$from>) -> TypedTemperature<$to> {
+                    temp.convert()
+                }
+            }
+        )*
+    };
+}
+
+typed_conversions!(Celsius => Fahrenheit, Kelvin, Rankine, Reaumur, Delisle, Newton, Romer);
+typed_conversions!(Fahrenheit => Celsius, Kelvin, Rankine, Reaumur, Delisle, Newton, Romer);
+typed_conversions!(Kelvin => Celsius, Fahrenheit, Rankine, Reaumur, Delisle, Newton, Romer);
+typed_conversions!(Rankine => Celsius, Fahrenheit, Kelvin, Reaumur, Delisle, Newton, Romer);
+typed_conversions!(Reaumur => Celsius, Fahrenheit, Kelvin, Rankine, Delisle, Newton, Romer);
+typed_conversions!(Delisle => Celsius, Fahrenheit, Kelvin, Rankine, Reaumur, Newton, Romer);
+typed_conversions!(Newton => Celsius, Fahrenheit, Kelvin, Rankine, Reaumur, Delisle, Romer);
+typed_conversions!(Romer => Celsius, Fahrenheit, Kelvin, Rankine, Reaumur, Delisle, Newton);
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+
+    use super::{Celsius, Delisle, Fahrenheit, Kelvin, Newton, Romer, TypedTemperature};
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+
+    fn needs_kelvin(temp: TypedTemperature<Kelvin>) -> f64 {
+        temp.value()
+    }
+
+    #[test]
+    fn typed_temperature_is_a_bare_f64() {
+        assert_eq!(size_of::<TypedTemperature<Celsius>>(), size_of::<f64>());
+    }
+
+    #[test]
+    fn from_converts_between_scales() {
+        let boiling = TypedTemperature::<Celsius>::new(100.0).expect("valid");
+        let fahrenheit: TypedTemperature<Fahrenheit> = boiling.into();
+        assert_eq!(fahrenheit.value(), 212.0);
+        assert_eq!(needs_kelvin(fahrenheit.into()), 373.15);
+        assert_eq!(boiling.convert::<Romer>().value(), 60.0);
+    }
+
+    #[test]
+    fn try_convert_reports_overflow() {
+        let huge = TypedTemperature::<Newton>::new(f64::MAX).expect("valid");
+        assert!(matches!(
+            huge.try_convert::<Kelvin>(),
+            Err(TemperatureError::OutOfRange {
+                unit: TemperatureUnit::Newton,
+                ..
+            })
+        ));
+        // Near the limit the conversion divides first instead of overflowing.
+        let large = TypedTemperature::<Fahrenheit>::new(f64::MAX).expect("valid");
+        assert!(large.try_convert::<Celsius>().is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn convert_panics_on_overflow() {
+        let huge = TypedTemperature::<Newton>::new(f64::MAX).expect("valid");
+        let _ = huge.convert::<Kelvin>();
+    }
+
+    #[test]
+    fn new_rejects_below_absolute_zero() {
+        assert!(TypedTemperature::<Kelvin>::new(-1.0).is_err());
+        assert!(TypedTemperature::<Delisle>::new(560.0).is_err());
+        assert!(TypedTemperature::<Fahrenheit>::new(-459.67).is_ok());
+    }
+
+    #[test]
+    fn bridges_to_dynamic_temperature() {
+        let dynamic = Temperature::new(32.0, TemperatureUnit::Fahrenheit).expect("valid");
+        let celsius: TypedTemperature<Celsius> = dynamic.into();
+        assert_eq!(celsius.value(), 0.0);
+        assert_eq!(celsius.unit(), TemperatureUnit::Celsius);
+
+        let back: Temperature = celsius.into();
+        assert_eq!(back.unit(), TemperatureUnit::Celsius);
+        assert_eq!(format!("{celsius}"), "0\u{00B0}C");
+    }
+
+    #[test]
+    fn parses_and_converts() {
+        let temp: TypedTemperature<Kelvin> = "0C".parse().expect("valid");
+        assert_eq!(temp.value(), 273.15);
+        assert!("-500C".parse::<TypedTemperature<Kelvin>>().is_err());
+    }
+
+    #[test]
+    fn delisle_orders_physically() {
+        let hot = TypedTemperature::<Delisle>::new(0.0).expect("valid");
+        let cold = TypedTemperature::<Delisle>::new(150.0).expect("valid");
+        assert!(hot > cold);
+    }
+}
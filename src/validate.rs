@@ -0,0 +1,245 @@
+//! Validation policies for new temperatures.
+//!
+//! `Temperature::new` and `FromStr` accept any finite reading that isn't
+//! below absolute zero. `Temperature::new_with` and
+//! `Temperature::parse_with` take a `Validator` instead, so a caller can
+//! narrow that to a plausible range with `Bounded`, or widen it to negative
+//! absolute temperatures with `NegativeKelvin`. NaN and infinite readings
+//! are rejected by every built-in policy.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::validate::Bounded;
+//! use convert_temp::{Temperature, TemperatureError, TemperatureUnit};
+//!
+//! let body = Bounded::new(
+//!     Temperature::new(25.0, TemperatureUnit::Celsius).unwrap(),
+//!     Temperature::new(45.0, TemperatureUnit::Celsius).unwrap(),
+//! );
+//!
+//! assert!(Temperature::<f64>::parse_with("98.6F", body).is_ok());
+//! let err = Temperature::new_with(120.0, TemperatureUnit::Fahrenheit, body).unwrap_err();
+//! assert!(matches!(err, TemperatureError::OutOfBounds { .. }));
+//! ```
+
+use core::cmp::Ordering;
+
+use crate::numeric::Scalar;
+use crate::scale::Affine;
+use crate::temperature::{Result, Temperature, TemperatureError, TemperatureUnit};
+
+/// A rule that decides which readings make a valid `Temperature`.
+///
+/// The trait is object safe, so a policy can be chosen at run time and
+/// passed as a `&dyn Validator` or `Box<dyn Validator>`.
+pub trait Validator {
+    /// Returns `Ok` if `value` is acceptable on the `unit` scale, or the
+    /// error to report. Readings stored in another `Scalar` are passed as
+    /// their nearest `f64`.
+    fn validate(&self, value: f64, unit: TemperatureUnit) -> Result<()>;
+}
+
+impl<V: Validator + ?Sized> Validator for &V {
+    fn validate(&self, value: f64, unit: TemperatureUnit) -> Result<()> {
+        (**self).validate(value, unit)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Validator + ?Sized> Validator for alloc::boxed::Box<V> {
+    fn validate(&self, value: f64, unit: TemperatureUnit) -> Result<()> {
+        (**self).validate(value, unit)
+    }
+}
+
+/// The default policy: finite readings at or above absolute zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AbsoluteZero;
+
+impl AbsoluteZero {
+    /// Checks a reading in its own representation, as `Temperature::new`
+    /// does, so that fixed-point values compare exactly and `f32` values
+    /// against absolute zero as rounded to `f32`.
+    pub(crate) fn check<T: Scalar>(value: T, unit: TemperatureUnit) -> Result<()> {
+        NegativeKelvin.validate(value.to_f64(), unit)?;
+
+        // Scales that run backwards, like Delisle, are bounded from above.
+        let below = if unit.scale().kelvin_per_degree.numerator < 0 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        if value.compare_absolute_zero(unit) == Some(below) {
+            Err(TemperatureError::BelowAbsoluteZero {
+                value: value.to_f64(),
+                unit,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Validator for AbsoluteZero {
+    fn validate(&self, value: f64, unit: TemperatureUnit) -> Result<()> {
+        AbsoluteZero::check(value, unit)
+    }
+}
+
+/// Accepts any finite reading, including negative absolute temperatures
+/// such as those of population-inverted spin systems.
+///
+/// A `Temperature` made with this policy is exempt from the usual guarantee
+/// that it is not below absolute zero. Conversions and comparisons still
+/// work, ordering by the kelvin value, so a negative absolute temperature
+/// compares as colder than absolute zero. Functions with a physical range,
+/// such as those of the `water` and `thermocouple` modules, reject it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NegativeKelvin;
+
+impl Validator for NegativeKelvin {
+    fn validate(&self, value: f64, unit: TemperatureUnit) -> Result<()> {
+        if value.is_finite() {
+            Ok(())
+        } else {
+            Err(TemperatureError::NonFinite { value, unit })
+        }
+    }
+}
+
+/// Accepts readings between two temperatures, inclusive, such as the
+/// plausible output of a sensor.
+///
+/// The bounds may be in any units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounded {
+    min: Temperature,
+    max: Temperature,
+}
+
+impl Bounded {
+    /// Creates a policy accepting readings from `min` to `max`.
+    ///
+    /// # Panics
+    /// Panics if `min` is hotter than `max`.
+    pub fn new(min: Temperature, max: Temperature) -> Bounded {
+        assert!(min <= max, "Bounded: {min} is hotter than {max}");
+        Bounded { min, max }
+    }
+
+    /// Returns the coldest accepted temperature.
+    pub fn min(&self) -> Temperature {
+        self.min
+    }
+
+    /// Returns the hottest accepted temperature.
+    pub fn max(&self) -> Temperature {
+        self.max
+    }
+}
+
+impl Validator for Bounded {
+    fn validate(&self, value: f64, unit: TemperatureUnit) -> Result<()> {
+        NegativeKelvin.validate(value, unit)?;
+
+        let kelvin = |value: f64, unit| Affine::between(unit, TemperatureUnit::Kelvin).apply(value);
+        let reading = kelvin(value, unit);
+        let min = kelvin(self.min.value(), self.min.unit());
+        let max = kelvin(self.max.value(), self.max.unit());
+        if reading < min || reading > max {
+            Err(TemperatureError::OutOfBounds {
+                value,
+                unit,
+                min: self.min,
+                max: self.max,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AbsoluteZero, Bounded, NegativeKelvin, Validator};
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+
+    fn celsius(value: f64) -> Temperature {
+        Temperature::new(value, TemperatureUnit::Celsius).expect("valid")
+    }
+
+    #[test]
+    fn absolute_zero_is_the_default() {
+        assert!(AbsoluteZero
+            .validate(-273.15, TemperatureUnit::Celsius)
+            .is_ok());
+        assert!(AbsoluteZero
+            .validate(-273.16, TemperatureUnit::Celsius)
+            .is_err());
+        assert_eq!(
+            Temperature::new_with(-1.0, TemperatureUnit::Kelvin, AbsoluteZero),
+            Temperature::new(-1.0, TemperatureUnit::Kelvin)
+        );
+    }
+
+    #[test]
+    fn negative_kelvin_allows_spin_temperatures() {
+        let spin =
+            Temperature::new_with(-0.5, TemperatureUnit::Kelvin, NegativeKelvin).expect("valid");
+        assert_eq!(spin.value(), -0.5);
+        let spin: Temperature = Temperature::parse_with("-2K", NegativeKelvin).expect("valid");
+        assert_eq!(spin.to(TemperatureUnit::Celsius).value(), -275.15);
+
+        let err =
+            Temperature::new_with(f64::NAN, TemperatureUnit::Kelvin, NegativeKelvin).unwrap_err();
+        assert!(matches!(err, TemperatureError::NonFinite { .. }));
+    }
+
+    #[test]
+    fn bounded_checks_across_units() {
+        let max = Temperature::new(257.0, TemperatureUnit::Fahrenheit).expect("valid");
+        let sensor = Bounded::new(celsius(-40.0), max);
+        assert!(Temperature::new_with(125.0, TemperatureUnit::Celsius, sensor).is_ok());
+        assert!(Temperature::new_with(-40.0, TemperatureUnit::Fahrenheit, sensor).is_ok());
+        assert!(Temperature::new_with(400.0, TemperatureUnit::Kelvin, sensor).is_err());
+
+        let err = Temperature::<f64>::parse_with("126C", sensor).unwrap_err();
+        assert_eq!(
+            format!("{err}"),
+            "126\u{00B0}C is outside -40\u{00B0}C to 257\u{00B0}F"
+        );
+        assert!(Temperature::<f64>::parse_with("12X", sensor).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn policies_can_be_chosen_at_runtime() {
+        let policies: Vec<Box<dyn Validator>> = vec![
+            Box::new(AbsoluteZero),
+            Box::new(NegativeKelvin),
+            Box::new(Bounded::new(celsius(-40.0), celsius(125.0))),
+        ];
+        let accepted: Vec<bool> = policies
+            .iter()
+            .map(|policy| Temperature::new_with(-5.0, TemperatureUnit::Kelvin, policy).is_ok())
+            .collect();
+        assert_eq!(accepted, [false, true, false]);
+
+        let allow_spin = true;
+        let policy: &dyn Validator = if allow_spin {
+            &NegativeKelvin
+        } else {
+            &AbsoluteZero
+        };
+        assert!(Temperature::<f64>::parse_with("-2K", policy).is_ok());
+        assert!(policy
+            .validate(f64::INFINITY, TemperatureUnit::Kelvin)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn bounded_rejects_inverted_bounds() {
+        let _ = Bounded::new(celsius(45.0), celsius(25.0));
+    }
+}
@@ -0,0 +1,192 @@
+//! The boiling point of water at a given pressure or altitude.
+//!
+//! `BOILING_POINT` is 100 °C, which only holds at sea level. Water boils
+//! where its saturation pressure equals the ambient pressure, so at 1,600 m
+//! it boils at about 94.6 °C. These functions use the saturation line of the
+//! IAPWS-IF97 industrial formulation, which replaces the older Antoine and
+//! Clausius–Clapeyron fits and runs both ways in closed form. It covers the
+//! triple point, 273.16 K and 611.657 Pa, to the critical point, 647.096 K
+//! and 22.064 MPa.
+//!
+//! Pressures are in pascals and altitudes in metres.
+//!
+//! # Examples
+//! ```rust
+//! use convert_temp::water;
+//! use convert_temp::TemperatureUnit;
+//!
+//! let lab = water::boiling_point_at_altitude(1600.0).unwrap();
+//! assert_eq!(format!("{lab:.1}"), "94.6\u{00B0}C");
+//!
+//! let sea_level = water::boiling_point(101_325.0).unwrap();
+//! assert_eq!(format!("{sea_level:.2}"), "99.97\u{00B0}C");
+//!
+//! let pressure = water::saturation_pressure(sea_level).unwrap();
+//! assert!((pressure - 101_325.0).abs() < 1e-6);
+//! ```
+
+use crate::atmosphere;
+use crate::temperature::{Result, Temperature, TemperatureError, TemperatureUnit};
+
+/// Coefficients `n1` to `n10` of the IAPWS-IF97 saturation-pressure
+/// equation.
+const N: [f64; 10] = [
+    0.116_705_214_527_67e4,
+    -0.724_213_167_032_06e6,
+    -0.170_738_469_400_92e2,
+    0.120_208_247_024_70e5,
+    -0.323_255_503_223_33e7,
+    0.149_151_086_135_30e2,
+    -0.482_326_573_615_91e4,
+    0.405_113_405_420_57e6,
+    -0.238_555_575_678_49,
+    0.650_175_348_447_98e3,
+];
+
+/// The triple point of water, in kelvin, where the saturation line starts.
+const TRIPLE_POINT: f64 = 273.16;
+
+/// The critical point of water, in kelvin, where the saturation line ends.
+const CRITICAL_POINT: f64 = 647.096;
+
+/// Saturation pressure at the triple point, in pascals.
+const TRIPLE_POINT_PRESSURE: f64 = 611.657;
+
+/// Saturation pressure at the critical point, in pascals.
+const CRITICAL_POINT_PRESSURE: f64 = 22.064e6;
+
+/// Returns the pressure, in pascals, at which water boils at `temp`.
+///
+/// Fails with `OutOfBounds` if `temp` is outside the saturation line, from
+/// the triple point to the critical point.
+pub fn saturation_pressure(temp: Temperature) -> Result<f64> {
+    let kelvin = temp.to(TemperatureUnit::Kelvin).value();
+    if !(TRIPLE_POINT..=CRITICAL_POINT).contains(&kelvin) {
+        return Err(TemperatureError::OutOfBounds {
+            value: temp.value(),
+            unit: temp.unit(),
+            min: Temperature::new_const(TRIPLE_POINT, TemperatureUnit::Kelvin),
+            max: Temperature::new_const(CRITICAL_POINT, TemperatureUnit::Kelvin),
+        });
+    }
+
+    let theta = kelvin + N[8] / (kelvin - N[9]);
+    let a = theta * theta + N[0] * theta + N[1];
+    let b = N[2] * theta * theta + N[3] * theta + N[4];
+    let c = N[5] * theta * theta + N[6] * theta + N[7];
+    let megapascals = (2.0 * c / (-b + (b * b - 4.0 * a * c).sqrt())).powi(4);
+    Ok(megapascals * 1e6)
+}
+
+/// Returns the temperature at which water boils at `pressure` pascals, in
+/// Celsius.
+///
+/// Fails with `OutOfDomain` if `pressure` is below the triple point or above
+/// the critical point.
+pub fn boiling_point(pressure: f64) -> Result<Temperature> {
+    if !(TRIPLE_POINT_PRESSURE..=CRITICAL_POINT_PRESSURE).contains(&pressure) {
+        return Err(TemperatureError::OutOfDomain {
+            quantity: "pressure",
+            value: pressure,
+            unit: "Pa",
+            min: TRIPLE_POINT_PRESSURE,
+            max: CRITICAL_POINT_PRESSURE,
+        });
+    }
+
+    let beta = (pressure / 1e6).powf(0.25);
+    let e = beta * beta + N[2] * beta + N[5];
+    let f = N[0] * beta * beta + N[3] * beta + N[6];
+    let g = N[1] * beta * beta + N[4] * beta + N[7];
+    let d = 2.0 * g / (-f - (f * f - 4.0 * e * g).sqrt());
+    let kelvin = (N[9] + d - ((N[9] + d) * (N[9] + d) - 4.0 * (N[8] + N[9] * d)).sqrt()) / 2.0;
+
+    let temp = Temperature::new(kelvin, TemperatureUnit::Kelvin)?;
+    Ok(temp.to(TemperatureUnit::Celsius))
+}
+
+/// Returns the temperature at which water boils at `altitude` metres above
+/// sea level, in Celsius, at the pressure of the standard atmosphere.
+///
+/// The altitude is geopotential, as in the `atmosphere` module. Weather
+/// moves the real pressure by a few percent, and with it the boiling point
+/// by a degree or so. Fails with `OutOfDomain` outside the standard
+/// atmosphere, or above about 34 km, where the pressure is below the triple
+/// point and water no longer boils.
+pub fn boiling_point_at_altitude(altitude: f64) -> Result<Temperature> {
+    boiling_point(atmosphere::pressure(altitude)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{boiling_point, boiling_point_at_altitude, saturation_pressure};
+    use crate::temperature::{Temperature, TemperatureError, TemperatureUnit};
+
+    fn kelvin(value: f64) -> Temperature {
+        Temperature::new(value, TemperatureUnit::Kelvin).expect("valid")
+    }
+
+    fn assert_close(actual: f64, expected: f64, relative: f64) {
+        assert!(
+            ((actual - expected) / expected).abs() < relative,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn matches_iapws_verification_values() {
+        // IAPWS-IF97, tables 35 and 36.
+        assert_close(
+            saturation_pressure(kelvin(300.0)).unwrap(),
+            0.353_658_941e4,
+            1e-8,
+        );
+        assert_close(
+            saturation_pressure(kelvin(500.0)).unwrap(),
+            0.263_889_776e7,
+            1e-8,
+        );
+        assert_close(
+            saturation_pressure(kelvin(600.0)).unwrap(),
+            0.123_443_146e8,
+            1e-8,
+        );
+
+        let boiling = |pressure| {
+            boiling_point(pressure)
+                .unwrap()
+                .to(TemperatureUnit::Kelvin)
+                .value()
+        };
+        assert_close(boiling(0.1e6), 0.372_755_919e3, 1e-8);
+        assert_close(boiling(1e6), 0.453_035_632e3, 1e-8);
+        assert_close(boiling(10e6), 0.584_149_488e3, 1e-8);
+    }
+
+    #[test]
+    fn boiling_point_falls_with_altitude() {
+        let sea_level = boiling_point_at_altitude(0.0).unwrap();
+        assert_eq!(sea_level.unit(), TemperatureUnit::Celsius);
+        assert!((sea_level.value() - 99.974).abs() < 1e-3);
+
+        let denver = boiling_point_at_altitude(1600.0).unwrap();
+        assert!((denver.value() - 94.6).abs() < 0.05, "{denver}");
+        let everest = boiling_point_at_altitude(8849.0).unwrap();
+        assert!((everest.value() - 70.2).abs() < 0.05, "{everest}");
+        assert!(boiling_point_at_altitude(-400.0).unwrap() > sea_level);
+    }
+
+    #[test]
+    fn rejects_inputs_off_the_saturation_line() {
+        let err = saturation_pressure(kelvin(200.0)).unwrap_err();
+        assert!(matches!(err, TemperatureError::OutOfBounds { .. }));
+        let err = boiling_point(100.0).unwrap_err();
+        assert_eq!(
+            format!("{err}"),
+            "pressure of 100 Pa is outside 611.657 to 22064000 Pa"
+        );
+        assert!(boiling_point_at_altitude(20_000.0).is_ok());
+        assert!(boiling_point_at_altitude(40_000.0).is_err());
+        assert!(boiling_point_at_altitude(100_000.0).is_err());
+    }
+}